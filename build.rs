@@ -0,0 +1,66 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const INPUT_DIRECTORY: &str = "input";
+
+/// Lists the inputs found in `input/YYYY/NN.txt`, ordered by year and day.
+fn find_inputs(directory: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut inputs = Vec::new();
+
+    for year_entry in fs::read_dir(directory).into_iter().flatten().flatten() {
+        let Some(year) = year_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse().ok())
+        else {
+            continue;
+        };
+
+        for day_entry in fs::read_dir(year_entry.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let file_name = day_entry.file_name();
+            let day = file_name
+                .to_str()
+                .and_then(|name| name.strip_suffix(".txt"))
+                .filter(|day| day.len() == 2)
+                .and_then(|day| day.parse::<u8>().ok());
+
+            if let Some(day @ 1..=25) = day {
+                inputs.push((year, day, day_entry.path()));
+            }
+        }
+    }
+
+    inputs.sort();
+    inputs
+}
+
+/// Generates the table of inputs embedded with the `embed-inputs` feature. Without it the table
+/// is empty, so inputs are always read at runtime.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    let manifest_directory = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let mut table = String::from("pub static INPUTS: &[(u16, u8, &str)] = &[\n");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let directory = manifest_directory.join(INPUT_DIRECTORY);
+        println!("cargo:rerun-if-changed={}", directory.display());
+
+        for (year, day, path) in find_inputs(&directory) {
+            table.push_str(&format!(
+                "    ({}, {}, include_str!({:?})),\n",
+                year,
+                day,
+                path.display().to_string()
+            ));
+        }
+    }
+
+    table.push_str("];\n");
+    let out_directory = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_directory.join("embedded_inputs.rs"), table).unwrap();
+}
@@ -0,0 +1,150 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Whether allocations are counted, which needs the `allocations` feature to install
+/// `CountingAllocator` as the global allocator.
+pub const ENABLED: bool = cfg!(feature = "allocations");
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The system allocator, counting every allocation and the bytes that are live at the same time.
+pub struct CountingAllocator;
+
+fn record(size: usize) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+
+        if !pointer.is_null() {
+            record(layout.size());
+        }
+
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+
+        if !pointer.is_null() {
+            record(layout.size());
+        }
+
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// A reallocation counts as an allocation of the new size that frees the old one.
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+
+        if !new_pointer.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+
+        new_pointer
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+    /// Highest number of bytes live at the same time, on top of those live before measuring.
+    pub peak: usize,
+}
+
+impl Allocations {
+    pub fn to_text(self) -> String {
+        format!(
+            "Allocated {} in {} allocations, peak {}.",
+            format_bytes(self.bytes),
+            self.count,
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024_f64),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576_f64),
+    }
+}
+
+/// Counts the allocations made while running `f`, or returns `None` if they are not counted.
+/// Allocations of other threads are counted as well, so nothing else should run meanwhile.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let (count, bytes) = (COUNT.load(Ordering::Relaxed), BYTES.load(Ordering::Relaxed));
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (value, Some(allocations))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::allocations::*;
+
+    #[test]
+    fn format_allocations() {
+        let allocations = Allocations {
+            count: 3,
+            bytes: 1536,
+            peak: 512,
+        };
+        assert_eq!(
+            allocations.to_text(),
+            "Allocated 1.5 KiB in 3 allocations, peak 512 B."
+        );
+        assert_eq!(format_bytes(3 * 1048576), "3.0 MiB");
+    }
+
+    #[test]
+    fn count_allocations_when_enabled() {
+        let (sum, allocations) = measure(|| {
+            let mut values: Vec<u64> = Vec::with_capacity(1000);
+            values.extend(0..1000);
+            values.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 499500);
+
+        assert_eq!(allocations.is_some(), ENABLED);
+
+        if let Some(allocations) = allocations {
+            assert!(allocations.count >= 1);
+            assert!(allocations.bytes >= 8000);
+            assert!(allocations.peak >= 8000);
+        }
+    }
+}
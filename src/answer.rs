@@ -0,0 +1,110 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Rows of a picture that has to be read by eye, e.g. the letters of Day 13 part 2.
+    Grid(Vec<String>),
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(i64::try_from(value).expect("The answer should fit in i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "text",
+            Answer::Grid(_) => "grid",
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Integer(value) => value.to_string(),
+            Answer::Text(value) => json_string(value),
+            Answer::Grid(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| json_string(row)).collect();
+                format!("[{}]", rows.join(","))
+            }
+        }
+    }
+}
+
+pub fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::*;
+
+    #[test]
+    fn convert_solver_results_into_answers() {
+        assert_eq!(Answer::from(1588_usize), Answer::Integer(1588));
+        assert_eq!(Answer::from(-3_i32), Answer::Integer(-3));
+        assert_eq!(
+            Answer::from("13579246899999".to_owned()),
+            Answer::Text("13579246899999".to_owned())
+        );
+    }
+
+    #[test]
+    fn format_answers_as_json() {
+        assert_eq!(Answer::Integer(7).to_json(), "7");
+        assert_eq!(Answer::from("a \"b\"\\").to_json(), r#""a \"b\"\\""#);
+        let grid = Answer::Grid(vec!["█ █".to_owned(), " █ ".to_owned()]);
+        assert_eq!(grid.to_json(), r#"["█ █"," █ "]"#);
+        assert_eq!(grid.to_string(), "█ █\n █ ");
+    }
+}
@@ -0,0 +1,384 @@
+use crate::answer::json_string;
+use crate::output::{as_ms, csv_field, Format};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub const CSV_HEADER: &str =
+    "year,day,part,title,iterations,min_ms,median_ms,p95_ms,mean_ms,stddev_ms,baseline_ms,change_pct,regression";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub warmup: u32,
+    pub iterations: u32,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    /// Slowdown of the median, in percent, above which a part is reported as a regression.
+    pub threshold: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            warmup: 3,
+            iterations: 10,
+            baseline: None,
+            save_baseline: None,
+            threshold: 10_f64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "There should be at least one sample");
+
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+        let count = samples.len();
+        let median = if count % 2 == 1 {
+            samples[count / 2]
+        } else {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        };
+        let p95 = samples[(count * 95).div_ceil(100) - 1];
+        let mean = samples.iter().sum::<Duration>() / count as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / (count.max(2) - 1) as f64;
+
+        Stats {
+            iterations: count,
+            min: samples[0],
+            median,
+            p95,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times without measuring, then `iterations` times with measuring.
+pub fn measure<T>(warmup: u32, iterations: u32, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        std::hint::black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+const MEASURABLE: Duration = Duration::from_micros(1);
+
+/// Median time of each part from an earlier run, stored one part per line as
+/// `year day part median_ms`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: HashMap<(u16, u8, u8), Duration>,
+}
+
+impl Baseline {
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        Baseline::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut baseline = Baseline::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let entry = match fields[..] {
+                [year, day, part, median_ms] => (|| {
+                    let key = (year.parse().ok()?, day.parse().ok()?, part.parse().ok()?);
+                    let median_ms: f64 = median_ms.parse().ok()?;
+                    Some((key, Duration::try_from_secs_f64(median_ms / 1000_f64).ok()?))
+                })(),
+                _ => None,
+            };
+
+            match entry {
+                Some((key, median)) => baseline.medians.insert(key, median),
+                None => {
+                    return Err(format!(
+                        "line {}: expected year, day, part and median time in milliseconds",
+                        index + 1
+                    ))
+                }
+            };
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, median: Duration) {
+        self.medians.insert((year, day, part), median);
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.medians.get(&(year, day, part)).copied()
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_string())
+            .map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn compare(
+        &self,
+        (year, day, part): (u16, u8, u8),
+        stats: &Stats,
+        threshold: f64,
+    ) -> Option<Comparison> {
+        let baseline = self.get(year, day, part)?;
+        // Medians too short to measure reliably, including a baseline of 0, are compared as if
+        // they took this long, so the ratio stays finite.
+        let floor = MEASURABLE.as_secs_f64();
+        let change = (stats.median.as_secs_f64().max(floor) / baseline.as_secs_f64().max(floor)
+            - 1_f64)
+            * 100_f64;
+
+        Some(Comparison {
+            baseline,
+            change,
+            regression: change > threshold,
+        })
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut entries: Vec<_> = self.medians.iter().collect();
+        entries.sort_unstable();
+        writeln!(f, "# year day part median_ms")?;
+
+        for ((year, day, part), median) in entries {
+            writeln!(f, "{} {} {} {:.6}", year, day, part, as_ms(*median))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    /// Change of the median against the baseline, in percent. Positive means slower.
+    pub change: f64,
+    pub regression: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    pub stats: Stats,
+    pub comparison: Option<Comparison>,
+}
+
+impl BenchRecord {
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let stats = &self.stats;
+        let mut text = format!(
+            "Day {}: {} (Part {}) min {:.3}ms, median {:.3}ms, p95 {:.3}ms, stddev {:.3}ms over {} iterations.",
+            self.day,
+            self.title,
+            self.part,
+            as_ms(stats.min),
+            as_ms(stats.median),
+            as_ms(stats.p95),
+            as_ms(stats.stddev),
+            stats.iterations
+        );
+
+        if let Some(comparison) = &self.comparison {
+            text.push_str(&format!(
+                " [{:+.1}% vs {:.3}ms baseline{}]",
+                comparison.change,
+                as_ms(comparison.baseline),
+                if comparison.regression {
+                    ", regression"
+                } else {
+                    ""
+                }
+            ));
+        }
+
+        text
+    }
+
+    pub fn to_json(&self) -> String {
+        let stats = &self.stats;
+        let comparison = match &self.comparison {
+            Some(comparison) => format!(
+                r#","baseline_ms":{:.3},"change_pct":{:.1},"regression":{}"#,
+                as_ms(comparison.baseline),
+                comparison.change,
+                comparison.regression
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            r#"{{"year":{},"day":{},"part":{},"title":{},"iterations":{},"min_ms":{:.3},"median_ms":{:.3},"p95_ms":{:.3},"mean_ms":{:.3},"stddev_ms":{:.3}{}}}"#,
+            self.year,
+            self.day,
+            self.part,
+            json_string(self.title),
+            stats.iterations,
+            as_ms(stats.min),
+            as_ms(stats.median),
+            as_ms(stats.p95),
+            as_ms(stats.mean),
+            as_ms(stats.stddev),
+            comparison
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let stats = &self.stats;
+        let comparison = match &self.comparison {
+            Some(comparison) => format!(
+                "{:.3},{:.1},{}",
+                as_ms(comparison.baseline),
+                comparison.change,
+                comparison.regression
+            ),
+            None => ",,".to_owned(),
+        };
+
+        format!(
+            "{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(self.title),
+            stats.iterations,
+            as_ms(stats.min),
+            as_ms(stats.median),
+            as_ms(stats.p95),
+            as_ms(stats.mean),
+            as_ms(stats.stddev),
+            comparison
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|&value| Duration::from_millis(value))
+            .collect()
+    }
+
+    #[test]
+    fn calculate_stats_of_samples() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert!((as_ms(stats.stddev) - 2.5_f64.sqrt()).abs() < 1e-6);
+
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(Stats::from_samples(&samples).p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn measure_requested_number_of_iterations() {
+        let mut calls = 0;
+        let stats = measure(2, 5, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.iterations, 5);
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let mut baseline =
+            Baseline::parse("# year day part median_ms\n2021 19 1 10.0\n2021 19 2 20.5\n").unwrap();
+        assert_eq!(
+            baseline.get(2021, 19, 2),
+            Some(Duration::from_micros(20500))
+        );
+
+        let stats = Stats::from_samples(&ms(&[12]));
+        let comparison = baseline.compare((2021, 19, 1), &stats, 10_f64).unwrap();
+        assert!((comparison.change - 20_f64).abs() < 1e-9);
+        assert!(comparison.regression);
+        assert!(
+            !baseline
+                .compare((2021, 19, 1), &stats, 25_f64)
+                .unwrap()
+                .regression
+        );
+        assert_eq!(baseline.compare((2021, 22, 1), &stats, 10_f64), None);
+        assert_eq!(baseline.compare((2022, 19, 1), &stats, 10_f64), None);
+
+        baseline.insert(2021, 20, 1, Duration::ZERO);
+        let comparison = baseline.compare((2021, 20, 1), &stats, 10_f64).unwrap();
+        assert!(comparison.change.is_finite());
+        let stats = Stats::from_samples(&[Duration::from_nanos(300)]);
+        assert_eq!(
+            baseline
+                .compare((2021, 20, 1), &stats, 10_f64)
+                .unwrap()
+                .change,
+            0_f64
+        );
+        baseline.medians.remove(&(2021, 20, 1));
+
+        baseline.insert(2021, 1, 1, Duration::from_micros(1500));
+        baseline.insert(2022, 1, 1, Duration::from_micros(500));
+        assert_eq!(
+            baseline.to_string(),
+            "# year day part median_ms\n2021 1 1 1.500000\n2021 19 1 10.000000\n2021 19 2 20.500000\n2022 1 1 0.500000\n"
+        );
+        assert_eq!(Baseline::parse(&baseline.to_string()), Ok(baseline));
+        assert!(Baseline::parse("19 1 10.0").is_err());
+        assert!(Baseline::parse("2021 19 1 fast").is_err());
+    }
+}
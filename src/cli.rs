@@ -0,0 +1,684 @@
+use crate::bench;
+use crate::http;
+use crate::input::{self, InputSource, DEFAULT_DIRECTORY};
+use crate::output::Format;
+use crate::registry;
+use crate::scaffold::FIRST_YEAR;
+use crate::submit::HISTORY_FILE_NAME;
+use crate::verify::ANSWERS_FILE_NAME;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code_2021 [OPTIONS]
+       advent_of_code_2021 fetch [--day <DAYS>] [--input <DIR>] [--force] [SERVER OPTIONS]
+       advent_of_code_2021 submit --day <DAY> --part <PART> [--input <PATH>] [SERVER OPTIONS]
+       advent_of_code_2021 new-day --day <DAY> --title <TITLE> [--year <YEAR>] [--input <DIR>]
+
+Commands:
+  fetch               Download missing inputs into the input directory
+  submit              Solve one part and submit its answer, unless
+                      submissions.txt with the inputs of the year shows it is wrong
+  new-day             Generate the modules of a day, register its solution and
+                      create empty input and example files, run from the
+                      root of the repository
+
+Options:
+  -y, --year <YEAR>   Run the puzzles of the selected event (default: the latest)
+  -d, --day <DAYS>    Run the selected days only, e.g. 15, 20-25 or 1,3,20-25
+  -p, --part <PART>   Run the selected part only: 1 or 2
+  -i, --input <PATH>  Read input from a file, from YEAR/NN.txt files in a
+                      directory, or from stdin when PATH is - (default: ./input)
+  -f, --format <FMT>  Print results as text, json (one object per line) or csv
+                      (default: text)
+  -j, --jobs <N>      Solve up to N days at the same time, or one per CPU when N
+                      is auto (default: 1)
+  -t, --timeout <SECONDS>
+                      Report a day as timed out when parsing its input or
+                      solving a part takes longer, and go on with the others
+  -v, --verify        Compare answers with the expected ones and fail on mismatch
+      --answers <PATH>
+                      Expected answers for --verify (default: answers.toml
+                      with the inputs of the year)
+  -w, --watch         Solve the examples and input of a single day again whenever
+                      they change, showing how the answers changed
+  -b, --bench         Benchmark each part instead of running it once
+      --warmup <N>    Untimed runs before benchmarking each part (default: 3)
+      --iterations <N>
+                      Timed runs of each part (default: 10)
+      --baseline <PATH>
+                      Compare median times with a saved baseline
+      --save-baseline <PATH>
+                      Save median times as a baseline
+      --threshold <PERCENT>
+                      Slowdown reported as a regression (default: 10)
+      --title <TITLE> Title of the puzzle, naming the modules of new-day
+  -a, --all           Run every solution (default)
+  -l, --list          List available solutions and exit
+  -h, --help          Print this help and exit
+
+Server options:
+      --base-url <URL>
+                      Use URL/YEAR/day/N/input and URL/YEAR/day/N/answer
+                      (default: https://adventofcode.com)
+      --session-file <PATH>
+                      Session token used when AOC_SESSION is not set
+                      (default: ./.session)
+      --force         Download inputs even if they are already cached";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Command {
+    #[default]
+    Solve,
+    /// Download missing inputs into the input directory.
+    Fetch,
+    /// Submit the answer of a single part.
+    Submit,
+    /// Generate the modules of a new day.
+    NewDay,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    /// Year of the event. `None` means the latest one.
+    pub year: Option<u16>,
+    pub days: Option<Vec<u8>>,
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub format: Format,
+    /// Number of days solved at the same time. `None` means one at a time.
+    pub jobs: Option<usize>,
+    /// Longest time allowed for parsing the input of a day or solving one of its parts.
+    pub timeout: Option<Duration>,
+    pub verify: bool,
+    pub answers: Option<PathBuf>,
+    pub watch: bool,
+    pub bench: bool,
+    pub bench_settings: bench::Settings,
+    pub command: Command,
+    pub server: http::Settings,
+    /// Download inputs that are already in the input directory as well.
+    pub force: bool,
+    pub title: Option<String>,
+    pub list: bool,
+    pub help: bool,
+}
+
+impl Options {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut all = false;
+        let mut args = args.into_iter().peekable();
+
+        options.command = match args.peek().map(String::as_str) {
+            Some("fetch") => Command::Fetch,
+            Some("submit") => Command::Submit,
+            Some("new-day") => Command::NewDay,
+            _ => Command::Solve,
+        };
+
+        if options.command != Command::Solve {
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag.to_owned(), Some(value)),
+                _ => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline_value
+                    .map(str::to_owned)
+                    .or_else(|| args.next())
+                    .ok_or(format!("Missing value for {name}."))
+            };
+
+            match flag.as_str() {
+                "-y" | "--year" => options.year = Some(parse_year(&value("--year")?)?),
+                "--title" => options.title = Some(value("--title")?),
+                "-d" | "--day" => {
+                    let days = parse_days(&value("--day")?)?;
+                    options.days.get_or_insert_with(Vec::new).extend(days);
+                }
+                "-p" | "--part" => options.part = Some(parse_part(&value("--part")?)?),
+                "-i" | "--input" => options.input = value("--input")?.parse()?,
+                "-f" | "--format" => options.format = value("--format")?.parse()?,
+                "-j" | "--jobs" => options.jobs = Some(parse_jobs(&value("--jobs")?)?),
+                "-t" | "--timeout" => options.timeout = Some(parse_timeout(&value("--timeout")?)?),
+                "-v" | "--verify" => options.verify = true,
+                "--answers" => options.answers = Some(PathBuf::from(value("--answers")?)),
+                "-w" | "--watch" => options.watch = true,
+                "-b" | "--bench" => options.bench = true,
+                "--warmup" => options.bench_settings.warmup = parse_count(&value("--warmup")?)?,
+                "--iterations" => {
+                    options.bench_settings.iterations = match parse_count(&value("--iterations")?)?
+                    {
+                        0 => return Err("Number of iterations should be at least 1.".to_owned()),
+                        iterations => iterations,
+                    }
+                }
+                "--baseline" => {
+                    options.bench_settings.baseline = Some(PathBuf::from(value("--baseline")?))
+                }
+                "--save-baseline" => {
+                    options.bench_settings.save_baseline =
+                        Some(PathBuf::from(value("--save-baseline")?))
+                }
+                "--threshold" => {
+                    options.bench_settings.threshold = parse_threshold(&value("--threshold")?)?
+                }
+                "--base-url" => options.server.base_url = value("--base-url")?,
+                "--session-file" => {
+                    options.server.session_file = PathBuf::from(value("--session-file")?)
+                }
+                "--force" => options.force = true,
+                "-a" | "--all" => all = true,
+                "-l" | "--list" => options.list = true,
+                "-h" | "--help" => options.help = true,
+                _ => return Err(format!("Unrecognized argument: {arg}.")),
+            }
+        }
+
+        if all && options.days.is_some() {
+            return Err("--all cannot be combined with --day.".to_owned());
+        }
+
+        if !options.bench && options.bench_settings != bench::Settings::default() {
+            return Err("Benchmark options require --bench.".to_owned());
+        }
+
+        if options.bench && options.verify {
+            return Err("--bench cannot be combined with --verify.".to_owned());
+        }
+
+        if options.bench && options.jobs.is_some() {
+            return Err("--bench cannot be combined with --jobs.".to_owned());
+        }
+
+        if options.bench && options.timeout.is_some() {
+            return Err("--bench cannot be combined with --timeout.".to_owned());
+        }
+
+        if options.watch {
+            let single_day = options.days.as_ref().is_some_and(|days| days.len() == 1);
+            let other_modes = options.bench
+                || options.verify
+                || options.list
+                || options.jobs.is_some()
+                || options.format != Format::default()
+                || options.command != Command::Solve;
+
+            if !single_day || other_modes {
+                return Err("--watch needs a single --day and no other mode.".to_owned());
+            }
+
+            if options.input == InputSource::Stdin {
+                return Err("--watch cannot read input from stdin.".to_owned());
+            }
+        }
+
+        if let Some(year) = options.year {
+            if options.command != Command::NewDay && !registry::years().any(|known| known == year) {
+                return Err(format!(
+                    "No solutions of {year}. Expected one of: {}.",
+                    registry::years()
+                        .map(|year| year.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+
+        let uses_server = matches!(options.command, Command::Fetch | Command::Submit);
+
+        if !uses_server && options.server != http::Settings::default() {
+            return Err("Server options require the fetch or submit command.".to_owned());
+        }
+
+        if options.command != Command::NewDay && options.title.is_some() {
+            return Err("--title requires the new-day command.".to_owned());
+        }
+
+        if options.command != Command::Fetch && options.force {
+            return Err("--force requires the fetch command.".to_owned());
+        }
+
+        if options.command != Command::Solve {
+            let solving_options = options.bench
+                || options.verify
+                || options.list
+                || options.jobs.is_some()
+                || options.timeout.is_some()
+                || options.format != Format::default();
+
+            if solving_options {
+                return Err(
+                    "Commands only accept --year, --day, --part, --input and their own options."
+                        .to_owned(),
+                );
+            }
+        }
+
+        if options.command == Command::NewDay {
+            let single_day = options.days.as_ref().is_some_and(|days| days.len() == 1);
+
+            if !single_day || options.title.is_none() || options.part.is_some() {
+                return Err("new-day needs a single --day and a --title, but no --part.".to_owned());
+            }
+        }
+
+        if options.command == Command::Submit {
+            let single_day = options.days.as_ref().is_some_and(|days| days.len() == 1);
+
+            if !single_day || options.part.is_none() {
+                return Err("submit needs a single --day and a --part.".to_owned());
+            }
+        }
+
+        if options.command == Command::Fetch && options.part.is_some() {
+            return Err("fetch downloads whole days and does not accept --part.".to_owned());
+        }
+
+        if matches!(options.command, Command::Fetch | Command::NewDay) {
+            // Inputs are saved into the directory, which does not have to exist yet.
+            options.input = match options.input {
+                InputSource::File(path) => InputSource::Directory(path),
+                InputSource::Stdin => return Err("--input should be a directory.".to_owned()),
+                directory => directory,
+            };
+        }
+
+        if let Some(days) = &mut options.days {
+            days.sort_unstable();
+            days.dedup();
+        }
+
+        Ok(options)
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        match &self.answers {
+            Some(path) => path.clone(),
+            None => self.input_directory().join(ANSWERS_FILE_NAME),
+        }
+    }
+
+    pub fn history_path(&self) -> PathBuf {
+        self.input_directory().join(HISTORY_FILE_NAME)
+    }
+
+    /// Answers and submissions are kept with the inputs of the selected year.
+    fn input_directory(&self) -> PathBuf {
+        let directory = match &self.input {
+            InputSource::Directory(directory) => directory.clone(),
+            _ => PathBuf::from(DEFAULT_DIRECTORY),
+        };
+        input::year_directory(&directory, self.year())
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(registry::latest_year())
+    }
+
+    pub fn selects(&self, year: u16, day: u8, part: u8) -> bool {
+        year == self.year()
+            && self.days.as_ref().map_or(true, |days| days.contains(&day))
+            && self
+                .part
+                .map_or(true, |selected_part| selected_part == part)
+    }
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.trim().parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(format!(
+            "Invalid year: {value}. Expected {FIRST_YEAR} or later."
+        )),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day: {value}. Expected a number from 1 to 25."
+        )),
+    }
+}
+
+fn parse_days(value: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+
+    for item in value.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+
+                if first > last {
+                    return Err(format!("Invalid range of days: {item}."));
+                }
+
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value.trim() {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part: {value}. Expected 1 or 2.")),
+    }
+}
+
+fn parse_count(value: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number: {value}."))
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    match value.trim() {
+        "auto" => Ok(std::thread::available_parallelism().map_or(1, |jobs| jobs.get())),
+        jobs => match jobs.parse::<usize>() {
+            Ok(jobs) if jobs > 0 => Ok(jobs),
+            _ => Err(format!(
+                "Invalid number of jobs: {value}. Expected a positive number or auto."
+            )),
+        },
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    match value.trim().trim_end_matches('s').parse::<f64>() {
+        Ok(seconds) if seconds > 0_f64 => {
+            Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
+        }
+        _ => Err(format!(
+            "Invalid timeout: {value}. Expected a positive number of seconds."
+        )),
+    }
+}
+
+fn parse_threshold(value: &str) -> Result<f64, String> {
+    match value.trim().trim_end_matches('%').parse::<f64>() {
+        Ok(threshold) if threshold >= 0_f64 => Ok(threshold),
+        _ => Err(format!(
+            "Invalid threshold: {value}. Expected a non-negative percentage."
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn select_everything_by_default() {
+        let options = parse(&[]).unwrap();
+        assert_eq!(options, Options::default());
+        let year = registry::latest_year();
+        assert_eq!(options.year(), year);
+        assert!(options.selects(year, 1, 1));
+        assert!(options.selects(year, 25, 1));
+        assert!(!options.selects(year - 1, 1, 1));
+    }
+
+    #[test]
+    fn select_single_day_and_part() {
+        let options = parse(&["-y", "2021", "--day", "15", "--part", "2"]).unwrap();
+        assert_eq!(options.days, Some(vec![15]));
+        assert_eq!(options.part, Some(2));
+        assert!(options.selects(2021, 15, 2));
+        assert!(!options.selects(2021, 15, 1));
+        assert!(!options.selects(2021, 14, 2));
+    }
+
+    #[test]
+    fn select_year() {
+        let options = parse(&["--year", "2021", "--day", "5"]).unwrap();
+        assert_eq!(options.year, Some(2021));
+        assert!(options.selects(2021, 5, 1));
+        assert_eq!(
+            parse(&["-y", "2020"]),
+            Err(format!(
+                "No solutions of 2020. Expected one of: {}.",
+                registry::years()
+                    .map(|year| year.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        );
+        assert!(parse(&["-y", "2014"]).is_err());
+    }
+
+    #[test]
+    fn select_ranges_and_lists_of_days() {
+        let options = parse(&["-d", "20-25", "--day=1,3,21"]).unwrap();
+        assert_eq!(options.days, Some(vec![1, 3, 20, 21, 22, 23, 24, 25]));
+    }
+
+    #[test]
+    fn select_input_source() {
+        assert_eq!(parse(&[]).unwrap().input, InputSource::default());
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, InputSource::Stdin);
+        assert_eq!(
+            parse(&["-i", "./test_input"]).unwrap().input,
+            InputSource::Directory("./test_input".into())
+        );
+        assert_eq!(
+            parse(&["--input=./test_input/2021/15.txt"]).unwrap().input,
+            InputSource::File("./test_input/2021/15.txt".into())
+        );
+    }
+
+    #[test]
+    fn select_output_format() {
+        assert_eq!(parse(&[]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["--format", "json"]).unwrap().format, Format::Json);
+        assert_eq!(parse(&["--format=csv"]).unwrap().format, Format::Csv);
+    }
+
+    #[test]
+    fn locate_expected_answers() {
+        assert_eq!(
+            parse(&["-y", "2021", "--verify"]).unwrap().answers_path(),
+            PathBuf::from("./input/2021/answers.toml")
+        );
+        assert_eq!(
+            parse(&["-y", "2021", "-v", "-i", "./test_input"])
+                .unwrap()
+                .answers_path(),
+            PathBuf::from("./test_input/2021/answers.toml")
+        );
+        assert_eq!(
+            parse(&["-v", "-i", "-", "--answers", "expected.toml"])
+                .unwrap()
+                .answers_path(),
+            PathBuf::from("expected.toml")
+        );
+    }
+
+    #[test]
+    fn select_number_of_jobs() {
+        assert_eq!(parse(&[]).unwrap().jobs, None);
+        assert_eq!(parse(&["--jobs", "4"]).unwrap().jobs, Some(4));
+        assert!(parse(&["-j", "auto"]).unwrap().jobs.unwrap() >= 1);
+    }
+
+    #[test]
+    fn select_timeout() {
+        assert_eq!(parse(&[]).unwrap().timeout, None);
+        assert_eq!(
+            parse(&["--timeout", "10"]).unwrap().timeout,
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            parse(&["-t", "0.25s"]).unwrap().timeout,
+            Some(Duration::from_millis(250))
+        );
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "soon"]).is_err());
+        assert!(parse(&["--bench", "--timeout", "1"]).is_err());
+    }
+
+    #[test]
+    fn configure_benchmark() {
+        let options = parse(&["--bench"]).unwrap();
+        assert!(options.bench);
+        assert_eq!(options.bench_settings, bench::Settings::default());
+
+        let options = parse(&[
+            "-b",
+            "--warmup=0",
+            "--iterations",
+            "50",
+            "--baseline",
+            "baseline.txt",
+            "--threshold",
+            "5%",
+        ])
+        .unwrap();
+        assert_eq!(options.bench_settings.warmup, 0);
+        assert_eq!(options.bench_settings.iterations, 50);
+        assert_eq!(
+            options.bench_settings.baseline,
+            Some(PathBuf::from("baseline.txt"))
+        );
+        assert_eq!(options.bench_settings.threshold, 5_f64);
+    }
+
+    #[test]
+    fn configure_fetch() {
+        let options = parse(&["fetch"]).unwrap();
+        assert_eq!(options.command, Command::Fetch);
+        assert_eq!(options.server, http::Settings::default());
+        assert!(!options.force);
+        assert_eq!(options.input, InputSource::default());
+
+        let options = parse(&[
+            "fetch",
+            "--day",
+            "1-3",
+            "--input",
+            "./new_input",
+            "--base-url=http://127.0.0.1:8080",
+            "--session-file",
+            "session.txt",
+            "--force",
+        ])
+        .unwrap();
+        assert_eq!(options.days, Some(vec![1, 2, 3]));
+        assert_eq!(options.input, InputSource::Directory("./new_input".into()));
+        assert_eq!(
+            options.server,
+            http::Settings {
+                base_url: "http://127.0.0.1:8080".to_owned(),
+                session_file: PathBuf::from("session.txt"),
+            }
+        );
+        assert!(options.force);
+    }
+
+    #[test]
+    fn configure_watch() {
+        let options = parse(&["--watch", "--day", "12", "--part", "2"]).unwrap();
+        assert!(options.watch);
+        assert_eq!(options.days, Some(vec![12]));
+
+        assert!(parse(&["--watch"]).is_err());
+        assert!(parse(&["-w", "-d", "1-2"]).is_err());
+        assert!(parse(&["-w", "-d", "1", "--bench"]).is_err());
+        assert!(parse(&["-w", "-d", "1", "-i", "-"]).is_err());
+        let options = parse(&["-w", "-d", "1", "-t", "2"]).unwrap();
+        assert_eq!(options.timeout, Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn configure_new_day() {
+        let options = parse(&[
+            "new-day",
+            "-y",
+            "2022",
+            "-d",
+            "1",
+            "--title",
+            "Calorie Counting",
+        ])
+        .unwrap();
+        assert_eq!(options.command, Command::NewDay);
+        assert_eq!(options.year(), 2022);
+        assert_eq!(options.days, Some(vec![1]));
+        assert_eq!(options.title.as_deref(), Some("Calorie Counting"));
+
+        assert!(parse(&["new-day", "-d", "1"]).is_err());
+        assert!(parse(&["new-day", "-d", "1-2", "--title", "Dive!"]).is_err());
+        assert!(parse(&["new-day", "-d", "2", "-p", "1", "--title", "Dive!"]).is_err());
+        assert!(parse(&["--title", "Dive!"]).is_err());
+    }
+
+    #[test]
+    fn configure_submit() {
+        let options = parse(&[
+            "submit",
+            "-y",
+            "2021",
+            "-d",
+            "7",
+            "-p",
+            "2",
+            "-i",
+            "./test_input",
+        ])
+        .unwrap();
+        assert_eq!(options.command, Command::Submit);
+        assert!(options.selects(2021, 7, 2));
+        assert!(!options.selects(2021, 7, 1));
+        assert_eq!(
+            options.history_path(),
+            PathBuf::from("./test_input/2021/submissions.txt")
+        );
+
+        let options = parse(&["submit", "-y", "2021", "-d", "7", "-p", "1", "-i", "-"]).unwrap();
+        assert_eq!(
+            options.history_path(),
+            PathBuf::from("./input/2021/submissions.txt")
+        );
+    }
+
+    #[test]
+    fn reject_invalid_arguments() {
+        assert!(parse(&["--day", "26"]).is_err());
+        assert!(parse(&["--day", "5-3"]).is_err());
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--all", "--day", "1"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--iterations", "5"]).is_err());
+        assert!(parse(&["--bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["--bench", "--threshold", "-5"]).is_err());
+        assert!(parse(&["--bench", "--verify"]).is_err());
+        assert!(parse(&["--bench", "--jobs", "2"]).is_err());
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert!(parse(&["--fast"]).is_err());
+        assert!(parse(&["--force"]).is_err());
+        assert!(parse(&["fetch", "--bench"]).is_err());
+        assert!(parse(&["fetch", "--input", "-"]).is_err());
+        assert!(parse(&["--day", "1", "fetch"]).is_err());
+        assert!(parse(&["fetch", "--part", "1"]).is_err());
+        assert!(parse(&["--base-url", "http://127.0.0.1:8080"]).is_err());
+        assert!(parse(&["submit", "--day", "1"]).is_err());
+        assert!(parse(&["submit", "--day", "1-2", "--part", "1"]).is_err());
+        assert!(parse(&["submit", "--day", "1", "--part", "1", "--force"]).is_err());
+    }
+}
@@ -1,2 +0,0 @@
-pub mod part_1;
-pub mod part_2;
@@ -1 +0,0 @@
-pub mod part_1;
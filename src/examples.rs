@@ -0,0 +1,164 @@
+use crate::input;
+use crate::registry;
+use crate::verify;
+
+pub const MANIFEST_FILE_NAME: &str = "examples.txt";
+
+/// An example input from the examples directory together with the expected answer of one part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub file_name: String,
+    pub expected: String,
+}
+
+impl Example {
+    /// Solves the example with the registered solution of its day and part and compares the
+    /// answer with the expected one.
+    pub fn check(&self) -> Result<(), String> {
+        let puzzle = registry::find(self.year, self.day, self.part)
+            .ok_or_else(|| format!("{self}: no solution"))?;
+        let input = input::read_example(self.year, &self.file_name)
+            .map_err(|error| format!("{self}: {error}"))?;
+        let answer = puzzle
+            .solve(&input)
+            .map_err(|error| format!("{self}: malformed input: {error}"))?;
+
+        match verify::matches(&self.expected, &answer) {
+            true => Ok(()),
+            false => Err(format!(
+                "{self}: expected {:?}, found {:?}",
+                self.expected,
+                answer.to_string()
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {} part {} with {}",
+            self.year, self.day, self.part, self.file_name
+        )
+    }
+}
+
+/// Reads the manifest kept with the examples of the year.
+pub fn read_manifest(year: u16) -> Result<Vec<Example>, String> {
+    let content =
+        input::read_example(year, MANIFEST_FILE_NAME).map_err(|error| error.to_string())?;
+    parse_manifest(year, &content).map_err(|error| format!("{}: {}", MANIFEST_FILE_NAME, error))
+}
+
+/// Parses a manifest with one example per line, made of the day, part, file name and expected
+/// answer separated by whitespace:
+///
+/// ```text
+/// # day part file answer
+/// 12 1 12_2.txt 19
+/// 13 2 13.txt "█████\n█   █\n█████"
+/// ```
+///
+/// Answers are written like single-line values of `answers.toml`, with `\n` separating the rows
+/// of a grid.
+pub fn parse_manifest(year: u16, content: &str) -> Result<Vec<Example>, String> {
+    let mut examples = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", index + 1, message);
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (day, rest) = split_field(line);
+        let (part, rest) = split_field(rest);
+        let (file_name, answer) = split_field(rest);
+
+        let day = match day.parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => day,
+            _ => return Err(error("expected a day from 1 to 25")),
+        };
+        let part = match part.parse::<u8>() {
+            Ok(part) if (1..=2).contains(&part) => part,
+            _ => return Err(error("expected part 1 or 2")),
+        };
+
+        if file_name.is_empty() || answer.is_empty() {
+            return Err(error("expected day, part, file and answer"));
+        }
+
+        examples.push(Example {
+            year,
+            day,
+            part,
+            file_name: file_name.to_owned(),
+            expected: verify::parse_value(answer).map_err(error)?,
+        });
+    }
+
+    Ok(examples)
+}
+
+fn split_field(line: &str) -> (&str, &str) {
+    match line.split_once(char::is_whitespace) {
+        Some((field, rest)) => (field, rest.trim_start()),
+        None => (line, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::*;
+
+    #[test]
+    fn parse_examples_manifest() {
+        let manifest = "# day part file answer\n\n01  1 01.txt 7\n13 2 13.txt \"█ █\\n █ \"\n";
+        let examples = parse_manifest(2021, manifest).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            examples[0],
+            Example {
+                year: 2021,
+                day: 1,
+                part: 1,
+                file_name: "01.txt".to_owned(),
+                expected: "7".to_owned(),
+            }
+        );
+        assert_eq!(examples[1].expected, "█ █\n █ ");
+    }
+
+    #[test]
+    fn reject_invalid_manifest() {
+        assert!(parse_manifest(2021, "26 1 26.txt 7").is_err());
+        assert!(parse_manifest(2021, "01 3 01.txt 7").is_err());
+        assert!(parse_manifest(2021, "01 1 01.txt").is_err());
+        assert_eq!(
+            parse_manifest(2021, "01 1 01.txt 7\n01 2 01.txt seven"),
+            Err("line 2: expected an integer or a string".to_owned())
+        );
+    }
+
+    #[test]
+    fn check_example_against_expected_answer() {
+        let mut example = Example {
+            year: 2021,
+            day: 1,
+            part: 1,
+            file_name: "01.txt".to_owned(),
+            expected: "7".to_owned(),
+        };
+        assert_eq!(example.check(), Ok(()));
+        example.expected = "8".to_owned();
+        assert_eq!(
+            example.check(),
+            Err("2021 day 1 part 1 with 01.txt: expected \"8\", found \"7\"".to_owned())
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::http;
+use crate::input::InputSource;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` into the directory of `year` in `directory`, unless it is already
+/// there and `force` is not set.
+pub fn fetch_input(
+    settings: &http::Settings,
+    token: &str,
+    directory: &Path,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<Fetched, String> {
+    let path = InputSource::Directory(directory.to_owned())
+        .path(year, day)
+        .unwrap();
+
+    if path.exists() && !force {
+        return Ok(Fetched::Cached);
+    }
+
+    let url = settings.url(year, day, "input");
+    let input = http::get(&url, token)?;
+
+    if input.trim().is_empty() {
+        return Err(format!("{url}: empty input"));
+    }
+
+    std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| std::fs::write(&path, input))
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::*;
+    use crate::http::stub;
+
+    #[test]
+    fn download_missing_inputs_and_cache_them() {
+        let (base_url, server) = stub::serve(2, |request| {
+            let day = request
+                .path
+                .strip_prefix("/2021/day/")
+                .and_then(|path| path.strip_suffix("/input"));
+
+            match (day, request.has_session("abc")) {
+                (Some(day), true) => ("200 OK", format!("input of day {day}\n")),
+                (_, false) => ("400 Bad Request", String::new()),
+                (None, true) => ("404 Not Found", String::new()),
+            }
+        });
+        let directory = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let settings = http::Settings {
+            base_url,
+            ..http::Settings::default()
+        };
+
+        assert_eq!(
+            fetch_input(&settings, "abc", &directory, 2021, 1, false),
+            Ok(Fetched::Downloaded)
+        );
+        assert_eq!(
+            std::fs::read_to_string(directory.join("2021/01.txt")).unwrap(),
+            "input of day 1\n"
+        );
+        assert_eq!(
+            fetch_input(&settings, "abc", &directory, 2021, 1, false),
+            Ok(Fetched::Cached)
+        );
+
+        let error = fetch_input(&settings, "wrong", &directory, 2021, 1, true).unwrap_err();
+        assert!(error.ends_with("/2021/day/1/input: HTTP/1.0 400 Bad Request"));
+        assert_eq!(server.join().unwrap().len(), 2);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or the displacement between two points, on a 3D integer lattice.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vector3 {
+    pub const ORIGIN: Vector3 = Vector3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn to_array(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> i64 {
+        let [x, y, z] = (self - other).abs().to_array();
+        x + y + z
+    }
+
+    /// Square of the Euclidean distance, which is exact, unlike the distance itself.
+    pub fn squared_distance(self, other: Self) -> i64 {
+        let [x, y, z] = (self - other).to_array();
+        x * x + y * y + z * z
+    }
+
+    pub fn euclidean_distance(self, other: Self) -> f64 {
+        (self.squared_distance(other) as f64).sqrt()
+    }
+}
+
+impl From<[i64; 3]> for Vector3 {
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// A rotation by multiples of 90 degrees, as a matrix whose rows are applied to `(x, y, z)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// The 24 orientations of a cube: each axis can face 6 directions, with 4 ways of turning
+    /// around it. They are the signed permutation matrices with a determinant of 1, starting with
+    /// the identity.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut rotations = Vec::with_capacity(24);
+
+        for permutation in PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+
+                for (row, &column) in permutation.iter().enumerate() {
+                    matrix[row][column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+
+                let rotation = Rotation(matrix);
+
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let [a, b, c] = self.0;
+        a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+            + a[2] * (b[0] * c[1] - b[1] * c[0])
+    }
+
+    /// The rotation undoing this one, which is its transpose.
+    pub fn inverse(self) -> Self {
+        Rotation(std::array::from_fn(|row| {
+            std::array::from_fn(|column| self.0[column][row])
+        }))
+    }
+}
+
+impl Mul<Vector3> for Rotation {
+    type Output = Vector3;
+
+    fn mul(self, vector: Vector3) -> Vector3 {
+        let [x, y, z] = self
+            .0
+            .map(|[a, b, c]| a * vector.x + b * vector.y + c * vector.z);
+        Vector3::new(x, y, z)
+    }
+}
+
+/// Composes two rotations: `(a * b) * v` is `a * (b * v)`.
+impl Mul for Rotation {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Rotation(std::array::from_fn(|row| {
+            std::array::from_fn(|column| (0..3).map(|k| self.0[row][k] * other.0[k][column]).sum())
+        }))
+    }
+}
+
+/// An axis-aligned box of lattice points from `min` to `max`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Cuboid {
+    pub fn new(min: Vector3, max: Vector3) -> Self {
+        debug_assert!(min.x <= max.x && min.y <= max.y && min.z <= max.z);
+        Self { min, max }
+    }
+
+    /// Number of points in the cuboid.
+    pub fn volume(&self) -> i64 {
+        let size = self.max - self.min + Vector3::new(1, 1, 1);
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, point: Vector3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// Returns `true` if every point of `other` is in the cuboid.
+    pub fn encloses(&self, other: &Cuboid) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Vector3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Vector3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then(|| Cuboid::new(min, max))
+    }
+
+    /// Points of the cuboid, ordered by `x`, then `y`, then `z`.
+    pub fn points(&self) -> impl Iterator<Item = Vector3> {
+        let (min, max) = (self.min, self.max);
+
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| Vector3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn combine_vectors_and_measure_distances() {
+        let (a, b) = (
+            Vector3::new(1105, -1205, 1229),
+            Vector3::new(-92, -2380, -20),
+        );
+        assert_eq!(a - b, Vector3::new(1197, 1175, 1249));
+        assert_eq!(b + (a - b), a);
+        assert_eq!(-a * 2, Vector3::new(-2210, 2410, -2458));
+        assert_eq!(a.manhattan_distance(b), 3621);
+        assert_eq!(Vector3::ORIGIN.squared_distance(Vector3::new(2, 3, 6)), 49);
+        assert_eq!(
+            Vector3::ORIGIN.euclidean_distance(Vector3::new(2, 3, 6)),
+            7_f64
+        );
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn form_group_of_24_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        let v = Vector3::new(1, 2, 3);
+        let images: HashSet<Vector3> = rotations.iter().map(|&rotation| rotation * v).collect();
+        assert_eq!(images.len(), 24);
+
+        for &a in &rotations {
+            assert_eq!(a * a.inverse(), Rotation::IDENTITY);
+
+            for &b in &rotations {
+                assert!(rotations.contains(&(a * b)));
+                assert_eq!((a * b) * v, a * (b * v));
+            }
+        }
+    }
+
+    #[test]
+    fn intersect_cuboids() {
+        let a = Cuboid::new(Vector3::new(10, 10, 10), Vector3::new(12, 12, 12));
+        let b = Cuboid::new(Vector3::new(11, 11, 11), Vector3::new(13, 13, 13));
+        assert_eq!(a.volume(), 27);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new(
+                Vector3::new(11, 11, 11),
+                Vector3::new(12, 12, 12)
+            ))
+        );
+        assert!(a.contains(Vector3::new(12, 10, 11)));
+        assert!(!a.encloses(&b));
+        assert_eq!(a.points().count(), 27);
+        assert_eq!(a.points().next(), Some(a.min));
+
+        let far = Cuboid::new(Vector3::new(20, 20, 20), Vector3::new(20, 20, 20));
+        assert_eq!(a.intersection(&far), None);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::parse::{grid_lines, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the neighbours sharing an edge, clockwise from the one above.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of the neighbours sharing an edge or a corner, clockwise from the one above.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row and indexed by `(x, y)` from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses `text`, a slice of `input`, as lines of equal width, converting each character with
+    /// `cell`. A character it rejects is reported as not being what was `expected`.
+    pub fn parse(
+        input: &str,
+        text: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines = grid_lines(input, text)?;
+        let mut cells = Vec::new();
+
+        for line in &lines {
+            for (index, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(ParseError::at(
+                            input,
+                            &line[index..],
+                            format!("expected {expected}, found {c:?}"),
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            width: cells.len() / lines.len(),
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => Some(&self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        match x < self.width && y < self.height {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// Positions of every cell, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position at offset `(dx, dy)` from `(x, y)`, if it is inside the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let (nx, ny) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        (nx < self.width && ny < self.height).then_some((nx, ny))
+    }
+
+    /// Position at offset `(dx, dy)` from `(x, y)`, wrapping around the edges of the grid.
+    pub fn wrapping_offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> (usize, usize) {
+        (
+            (x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    pub fn neighbours_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    pub fn neighbours_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Neighbours sharing an edge, where the grid continues on the opposite side past each edge.
+    pub fn wrapping_neighbours_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |&delta| self.wrapping_offset(position, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "The column should be inside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells of the square of side `2 * radius + 1` centred on `(x, y)`, row by row. The centre
+    /// may lie outside the grid, and so may any cell of the window, which is then `None`.
+    pub fn window(
+        &self,
+        (x, y): (isize, isize),
+        radius: isize,
+    ) -> impl Iterator<Item = Option<&T>> + '_ {
+        (y - radius..=y + radius).flat_map(move |ny| {
+            (x - radius..=x + radius).map(move |nx| {
+                match usize::try_from(nx).ok().zip(usize::try_from(ny).ok()) {
+                    Some(position) => self.get(position),
+                    None => None,
+                }
+            })
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses `text`, a slice of `input`, as lines of decimal digits of equal width.
+    pub fn parse_digits(input: &str, text: &str) -> Result<Self, ParseError> {
+        Self::parse(input, text, "a digit", |c| {
+            c.to_digit(10).map(|digit| digit as u8)
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .expect("The position should be inside the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .expect("The position should be inside the grid")
+    }
+}
+
+/// Prints the cells of each row next to each other, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    const MAP: &str = "123\n456\n";
+
+    #[test]
+    fn parse_and_display_grid() {
+        let grid = Grid::parse_digits(MAP, MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.to_string(), MAP);
+
+        let input = "#.\n.o\n";
+        let error = Grid::parse(input, input, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+        assert_eq!(
+            error,
+            Err(ParseError::new(2, 2, "expected '#' or '.', found 'o'"))
+        );
+        assert!(Grid::parse_digits("12\n3\n", "12\n3\n").is_err());
+    }
+
+    #[test]
+    fn find_neighbours_of_cell() {
+        let grid = Grid::parse_digits(MAP, MAP).unwrap();
+        let neighbours: Vec<_> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(neighbours, [(1, 0), (0, 1)]);
+        let neighbours: Vec<_> = grid.neighbours_8((1, 1)).collect();
+        assert_eq!(neighbours, [(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]);
+        let neighbours: Vec<_> = grid.wrapping_neighbours_4((0, 0)).collect();
+        assert_eq!(neighbours, [(0, 1), (1, 0), (0, 1), (2, 0)]);
+        assert_eq!(grid.wrapping_offset((2, 1), (1, 1)), (0, 0));
+    }
+
+    #[test]
+    fn view_rows_columns_and_windows() {
+        let mut grid = Grid::parse_digits(MAP, MAP).unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+
+        let window: Vec<_> = grid.window((0, 0), 1).map(|cell| cell.copied()).collect();
+        assert_eq!(
+            window,
+            [
+                None,
+                None,
+                None,
+                None,
+                Some(1),
+                Some(2),
+                None,
+                Some(4),
+                Some(5)
+            ]
+        );
+
+        grid[(1, 1)] = 0;
+        assert_eq!(
+            grid.map(|&value| value > 2)
+                .values()
+                .filter(|&&high| high)
+                .count(),
+            3
+        );
+        let positions: Vec<_> = grid.iter().filter(|(_, &value)| value == 0).collect();
+        assert_eq!(positions, [((1, 1), &0)]);
+        assert_eq!(
+            Grid::from_fn(2, 2, |(x, y)| x + y),
+            Grid {
+                width: 2,
+                height: 2,
+                cells: vec![0, 1, 1, 2]
+            }
+        );
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..\n");
+    }
+}
@@ -0,0 +1,407 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const DEFAULT_SESSION_FILE: &str = ".session";
+const USER_AGENT: &str = "github.com/rafald1/advent_of_code_2021";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where the puzzle server is and how to log in to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// URL of the server, to which `/YEAR/day/N/...` is appended.
+    pub base_url: String,
+    /// File holding the session token, used when the environment variable is not set.
+    pub session_file: PathBuf,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session_file: PathBuf::from(DEFAULT_SESSION_FILE),
+        }
+    }
+}
+
+impl Settings {
+    pub fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!(
+            "{}/{}/day/{}/{}",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day,
+            page
+        )
+    }
+
+    /// Reads the session cookie of a logged-in user, from the environment variable if it is set
+    /// and from the session file otherwise.
+    pub fn session_token(&self) -> Result<String, String> {
+        self.session_token_from(std::env::var(SESSION_VARIABLE).ok())
+    }
+
+    /// Same as `session_token`, with `variable` as the value of the environment variable.
+    fn session_token_from(&self, variable: Option<String>) -> Result<String, String> {
+        let (token, source) = match variable {
+            Some(token) if !token.trim().is_empty() => (token, SESSION_VARIABLE.to_owned()),
+            _ => {
+                let token = std::fs::read_to_string(&self.session_file).map_err(|error| {
+                    format!(
+                        "No session token. Set {} or save it in {} ({}).",
+                        SESSION_VARIABLE,
+                        self.session_file.display(),
+                        error
+                    )
+                })?;
+                (token, self.session_file.display().to_string())
+            }
+        };
+
+        match token.trim() {
+            "" => Err(format!("Session token in {} is empty.", source)),
+            // The token goes into a header line, which other characters could end or extend.
+            token if !token.bytes().all(is_cookie_octet) => Err(format!(
+                "Session token in {} has characters not allowed in a cookie.",
+                source
+            )),
+            token => Ok(token.to_owned()),
+        }
+    }
+}
+
+/// Characters allowed in a cookie value: printable ASCII except whitespace, `"`, `,`, `;` and `\`.
+fn is_cookie_octet(byte: u8) -> bool {
+    matches!(byte, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+}
+
+pub fn get(url: &str, token: &str) -> Result<String, String> {
+    request(url, token, None)
+}
+
+/// Posts a form, given as `key=value` pairs joined with `&`, and returns the response body.
+pub fn post_form(url: &str, token: &str, form: &str) -> Result<String, String> {
+    request(url, token, Some(form))
+}
+
+/// Plain HTTP is handled here, which is enough for a local server. HTTPS is left to `curl`.
+fn request(url: &str, token: &str, form: Option<&str>) -> Result<String, String> {
+    if let Some(address) = url.strip_prefix("http://") {
+        request_over_http(url, address, token, form)
+    } else if url.starts_with("https://") {
+        request_with_curl(url, token, form)
+    } else {
+        Err(format!(
+            "{url}: unsupported URL, expected http:// or https://"
+        ))
+    }
+}
+
+fn request_over_http(
+    url: &str,
+    address: &str,
+    token: &str,
+    form: Option<&str>,
+) -> Result<String, String> {
+    let (host, path) = match address.find('/') {
+        Some(index) => address.split_at(index),
+        None => (address, "/"),
+    };
+    let host_and_port = match host.contains(':') {
+        true => host.to_owned(),
+        false => format!("{host}:80"),
+    };
+    let error = |error: std::io::Error| format!("{url}: {error}");
+
+    let mut request = format!(
+        "{} {path} HTTP/1.0\r\nHost: {host}\r\nUser-Agent: {USER_AGENT}\r\nCookie: session={token}\r\n",
+        if form.is_some() { "POST" } else { "GET" }
+    );
+
+    match form {
+        Some(form) => request.push_str(&format!(
+            "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{form}",
+            form.len()
+        )),
+        None => request.push_str("\r\n"),
+    }
+
+    let mut stream = TcpStream::connect(host_and_port).map_err(error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(error)?;
+    stream.write_all(request.as_bytes()).map_err(error)?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).map_err(error)?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| format!("{url}: malformed response"))?;
+    let status = head.lines().next().unwrap_or_default();
+
+    match status.split_whitespace().nth(1) {
+        Some("200") => Ok(body.to_owned()),
+        _ => Err(format!("{url}: {status}")),
+    }
+}
+
+fn request_with_curl(url: &str, token: &str, form: Option<&str>) -> Result<String, String> {
+    // The token is passed through stdin, so it does not show up in the list of processes.
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("{url}: unable to run curl: {error}"))?;
+
+    curl.stdin
+        .take()
+        .unwrap()
+        .write_all(curl_config(url, token, form).as_bytes())
+        .map_err(|error| format!("{url}: {error}"))?;
+
+    let output = curl
+        .wait_with_output()
+        .map_err(|error| format!("{url}: {error}"))?;
+
+    match output.status.success() {
+        true => String::from_utf8(output.stdout).map_err(|error| format!("{url}: {error}")),
+        false => Err(format!(
+            "{url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+    }
+}
+
+fn curl_config(url: &str, token: &str, form: Option<&str>) -> String {
+    let mut config = format!(
+        "url = \"{}\"\nuser-agent = \"{USER_AGENT}\"\nheader = \"Cookie: session={}\"\nmax-time = {}\n",
+        escape(url),
+        escape(token),
+        TIMEOUT.as_secs()
+    );
+
+    if let Some(form) = form {
+        config.push_str(&format!("data = \"{}\"\n", escape(form)));
+    }
+
+    config
+}
+
+/// Escapes a value of the curl config, so it stays within its quotes.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Percent-encodes a value of a form.
+pub fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// A local server standing in for the puzzle server in tests.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    #[derive(Debug)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn has_session(&self, token: &str) -> bool {
+            self.headers
+                .iter()
+                .any(|header| *header == format!("Cookie: session={token}"))
+        }
+    }
+
+    /// Handles `requests` requests, one per connection, answering each with the status line and
+    /// body returned by `respond`. Returns the base URL and the handled requests.
+    pub fn serve(
+        requests: usize,
+        respond: fn(&Request) -> (&'static str, String),
+    ) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = std::thread::spawn(move || {
+            let mut handled = Vec::new();
+
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut lines = Vec::new();
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    match line.trim_end() {
+                        "" => break,
+                        line => lines.push(line.to_owned()),
+                    }
+                }
+
+                let length = lines
+                    .iter()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request_line = lines.remove(0);
+                let mut words = request_line.split_whitespace();
+                let request = Request {
+                    method: words.next().unwrap().to_owned(),
+                    path: words.next().unwrap().to_owned(),
+                    headers: lines,
+                    body: String::from_utf8(body).unwrap(),
+                };
+
+                let (status, body) = respond(&request);
+                write!(stream, "HTTP/1.0 {status}\r\n\r\n{body}").unwrap();
+                handled.push(request);
+            }
+
+            handled
+        });
+
+        (base_url, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::*;
+
+    #[test]
+    fn send_requests_to_local_server() {
+        let (base_url, server) = stub::serve(3, |request| match request.has_session("abc") {
+            true => ("200 OK", format!("{} {}", request.method, request.body)),
+            false => ("400 Bad Request", String::new()),
+        });
+        let settings = Settings {
+            base_url,
+            ..Settings::default()
+        };
+
+        assert_eq!(
+            settings.url(2021, 7, "input"),
+            format!("{}/2021/day/7/input", settings.base_url)
+        );
+        assert_eq!(
+            get(&settings.url(2021, 7, "input"), "abc"),
+            Ok("GET ".to_owned())
+        );
+        assert_eq!(
+            post_form(&settings.url(2021, 7, "answer"), "abc", "level=1&answer=37"),
+            Ok("POST level=1&answer=37".to_owned())
+        );
+        assert!(get(&settings.url(2021, 7, "input"), "wrong")
+            .unwrap_err()
+            .ends_with("/2021/day/7/input: HTTP/1.0 400 Bad Request"));
+
+        let paths: Vec<String> = server
+            .join()
+            .unwrap()
+            .into_iter()
+            .map(|request| request.path)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/2021/day/7/input",
+                "/2021/day/7/answer",
+                "/2021/day/7/input"
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_unsupported_url() {
+        assert_eq!(
+            get("ftp://example.com/day/1/input", "abc"),
+            Err(
+                "ftp://example.com/day/1/input: unsupported URL, expected http:// or https://"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn read_session_token_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc_session_{}", std::process::id()));
+        let settings = Settings {
+            session_file: path.clone(),
+            ..Settings::default()
+        };
+
+        std::fs::write(&path, "abc\n").unwrap();
+        assert_eq!(settings.session_token_from(None), Ok("abc".to_owned()));
+        assert_eq!(
+            settings.session_token_from(Some(" ".to_owned())),
+            Ok("abc".to_owned())
+        );
+        // A token set in the environment takes precedence over the file.
+        assert_eq!(
+            settings.session_token_from(Some("xyz".to_owned())),
+            Ok("xyz".to_owned())
+        );
+        assert_eq!(
+            settings.session_token_from(Some("xyz\r\nX-Injected: 1".to_owned())),
+            Err(format!(
+                "Session token in {} has characters not allowed in a cookie.",
+                SESSION_VARIABLE
+            ))
+        );
+        std::fs::write(&path, "abc; d=e\n").unwrap();
+        assert!(settings.session_token_from(None).is_err());
+        std::fs::write(&path, "\n").unwrap();
+        assert!(settings.session_token_from(None).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert!(settings.session_token_from(None).is_err());
+    }
+
+    #[test]
+    fn escape_values_of_curl_config() {
+        let config = curl_config("https://example.com", "a\"b\\c\nurl = x", Some("level=1"));
+        assert!(config.contains("header = \"Cookie: session=a\\\"b\\\\c\\nurl = x\"\n"));
+        assert!(config.ends_with("data = \"level=1\"\n"));
+        assert_eq!(config.lines().count(), 5);
+    }
+
+    #[test]
+    fn encode_form_values() {
+        assert_eq!(encode("92969593497992"), "92969593497992");
+        assert_eq!(encode("a b&c=d"), "a%20b%26c%3Dd");
+    }
+}
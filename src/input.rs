@@ -0,0 +1,166 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_DIRECTORY: &str = "./input";
+pub const EXAMPLES_DIRECTORY: &str = "./test_input";
+
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(PathBuf::from(DEFAULT_DIRECTORY))
+    }
+}
+
+impl std::str::FromStr for InputSource {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "" => Err("Input path should not be empty."),
+            "-" => Ok(InputSource::Stdin),
+            path if Path::new(path).is_dir() => Ok(InputSource::Directory(PathBuf::from(path))),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Directory(path) => write!(f, "{}", path.display()),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl InputSource {
+    /// Returns `true` if the source can provide a different input for each day.
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, InputSource::Directory(_))
+    }
+
+    /// Inputs in a directory are kept in a subdirectory per year, e.g. `input/2021/01.txt`.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Directory(directory) => {
+                Some(year_directory(directory, year).join(format!("{:02}.txt", day)))
+            }
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Inputs missing from the default directory are taken from the binary when they were embedded
+    /// at build time, see the `embed-inputs` feature, so edits of the files still take effect.
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        match self.path(year, day) {
+            Some(path) => match read_file(&path) {
+                Err(error)
+                    if error.kind() == io::ErrorKind::NotFound
+                        && *self == InputSource::default() =>
+                {
+                    embedded(year, day).map(str::to_owned).ok_or(error)
+                }
+                read => read,
+            },
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Returns the input embedded at build time, if any.
+pub fn embedded(year: u16, day: u8) -> Option<&'static str> {
+    embedded::INPUTS
+        .iter()
+        .find(|(input_year, input_day, _)| (*input_year, *input_day) == (year, day))
+        .map(|(_, _, input)| *input)
+}
+
+pub fn year_directory(directory: &Path, year: u16) -> PathBuf {
+    directory.join(year.to_string())
+}
+
+/// Reads an example input, e.g. `01.txt` or `22_2.txt`, from the examples directory of the year.
+pub fn read_example(year: u16, file_name: &str) -> io::Result<String> {
+    read_file(&year_directory(Path::new(EXAMPLES_DIRECTORY), year).join(file_name))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn resolve_path_of_each_day() {
+        let source = InputSource::default();
+        assert_eq!(
+            source.path(2021, 7),
+            Some(PathBuf::from("./input/2021/07.txt"))
+        );
+        assert_eq!(
+            source.path(2022, 21),
+            Some(PathBuf::from("./input/2022/21.txt"))
+        );
+
+        let source: InputSource = "./test_input".parse().unwrap();
+        assert_eq!(
+            source.path(2021, 1),
+            Some(PathBuf::from("./test_input/2021/01.txt"))
+        );
+
+        let source: InputSource = "./test_input/2021/16.txt".parse().unwrap();
+        assert_eq!(
+            source.path(2021, 3),
+            Some(PathBuf::from("./test_input/2021/16.txt"))
+        );
+
+        assert_eq!("-".parse::<InputSource>(), Ok(InputSource::Stdin));
+        assert_eq!(InputSource::Stdin.path(2021, 1), None);
+    }
+
+    #[test]
+    fn read_input_of_selected_day() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        assert!(source.read(2021, 1).unwrap().starts_with("199\n200"));
+        assert!(source.read(2021, 24).is_err());
+        assert!(source.read(2020, 1).is_err());
+        assert_eq!(
+            read_example(2021, "01.txt").unwrap(),
+            source.read(2021, 1).unwrap()
+        );
+        let path = year_directory(Path::new(EXAMPLES_DIRECTORY), 2021).join("24.txt");
+        assert!(read_example(2021, "24.txt")
+            .unwrap_err()
+            .to_string()
+            .starts_with(&format!("{}: ", path.display())));
+    }
+
+    #[test]
+    fn embed_inputs_of_default_directory_only() {
+        for &(year, day, input) in embedded::INPUTS {
+            assert_eq!(embedded(year, day), Some(input));
+            assert_eq!(InputSource::default().read(year, day).unwrap(), input);
+        }
+
+        assert_eq!(embedded(2021, 26), None);
+    }
+}
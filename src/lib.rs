@@ -0,0 +1,21 @@
+pub mod allocations;
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod examples;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+pub mod year_2021;
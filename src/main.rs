@@ -1,90 +1,524 @@
-mod day_01_sonar_sweep;
-mod day_02_dive;
-mod day_03_binary_diagnostic;
-mod day_04_giant_squid;
-mod day_05_hydrothermal_venture;
-mod day_06_lanternfish;
-mod day_07_the_treachery_of_whales;
-mod day_08_seven_segment_search;
-mod day_09_smoke_basin;
-mod day_10_syntax_scoring;
-mod day_11_dumbo_octopus;
-mod day_12_passage_pathing;
-mod day_13_transparent_origami;
-mod day_14_extended_polymerization;
-mod day_15_chiton;
-mod day_16_packet_decoder;
-mod day_17_trick_shot;
-mod day_18_snailfish;
-mod day_19_beacon_scanner;
-mod day_20_trench_map;
-mod day_21_dirac_dice;
-mod day_22_reactor_reboot;
-mod day_23_amphipod;
-mod day_24_arithmetic_logic_unit;
-mod day_25_sea_cucumber;
+use advent_of_code_2021::allocations;
+use advent_of_code_2021::answer::Answer;
+use advent_of_code_2021::bench::{self, Baseline, BenchRecord};
+use advent_of_code_2021::cli::Command;
+use advent_of_code_2021::fetch::{self, Fetched};
+use advent_of_code_2021::input::{self, InputSource, EXAMPLES_DIRECTORY};
+use advent_of_code_2021::output::{Format, Record, CSV_HEADER};
+use advent_of_code_2021::registry::Puzzle;
+use advent_of_code_2021::runner::{self, DayError, Solved};
+use advent_of_code_2021::submit::{self, Attempt, History, Outcome};
+use advent_of_code_2021::verify::{Answers, Verdict};
+use advent_of_code_2021::watch::{self, Run, Snapshot};
+use advent_of_code_2021::{cli, examples, registry, scaffold};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 fn main() {
-    let solutions = [
-        day_01_sonar_sweep::part_1::solve,
-        day_01_sonar_sweep::part_2::solve,
-        day_02_dive::part_1::solve,
-        day_02_dive::part_2::solve,
-        day_03_binary_diagnostic::part_1::solve,
-        day_03_binary_diagnostic::part_2::solve,
-        day_04_giant_squid::part_1::solve,
-        day_04_giant_squid::part_2::solve,
-        day_05_hydrothermal_venture::part_1::solve,
-        day_05_hydrothermal_venture::part_2::solve,
-        day_06_lanternfish::part_1::solve,
-        day_06_lanternfish::part_2::solve,
-        day_07_the_treachery_of_whales::part_1::solve,
-        day_07_the_treachery_of_whales::part_2::solve,
-        day_08_seven_segment_search::part_1::solve,
-        day_08_seven_segment_search::part_2::solve,
-        day_09_smoke_basin::part_1::solve,
-        day_09_smoke_basin::part_2::solve,
-        day_10_syntax_scoring::part_1::solve,
-        day_10_syntax_scoring::part_2::solve,
-        day_11_dumbo_octopus::part_1::solve,
-        day_11_dumbo_octopus::part_2::solve,
-        day_12_passage_pathing::part_1::solve,
-        day_12_passage_pathing::part_2::solve,
-        day_13_transparent_origami::part_1::solve,
-        day_13_transparent_origami::part_2::solve,
-        day_14_extended_polymerization::part_1::solve,
-        day_14_extended_polymerization::part_2::solve,
-        day_15_chiton::part_1::solve,
-        day_15_chiton::part_2::solve,
-        day_16_packet_decoder::part_1::solve,
-        day_16_packet_decoder::part_2::solve,
-        day_17_trick_shot::part_1::solve,
-        day_17_trick_shot::part_2::solve,
-        day_18_snailfish::part_1::solve,
-        day_18_snailfish::part_2::solve,
-        day_19_beacon_scanner::part_1::solve,
-        day_19_beacon_scanner::part_2::solve,
-        day_20_trench_map::part_1::solve,
-        day_20_trench_map::part_2::solve,
-        day_21_dirac_dice::part_1::solve,
-        day_21_dirac_dice::part_2::solve,
-        day_22_reactor_reboot::part_1::solve,
-        day_22_reactor_reboot::part_2::solve,
-        day_23_amphipod::part_1::solve,
-        day_23_amphipod::part_2::solve,
-        day_24_arithmetic_logic_unit::part_1::solve,
-        day_24_arithmetic_logic_unit::part_2::solve,
-        day_25_sea_cucumber::part_1::solve,
-    ];
-
-    for solution in solutions {
-        let start = std::time::Instant::now();
-        let result = solution();
-        let duration = start.elapsed();
+    let options = match cli::Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    match options.command {
+        Command::Fetch => std::process::exit(fetch(&options)),
+        Command::Submit => std::process::exit(submit(&options)),
+        Command::NewDay => std::process::exit(new_day(&options)),
+        Command::Solve => {}
+    }
+
+    let puzzles: Vec<&Puzzle> = registry::puzzles()
+        .filter(|puzzle| options.selects(puzzle.year, puzzle.day, puzzle.part))
+        .collect();
+
+    if puzzles.is_empty() {
+        eprintln!("No solutions match the selected days and parts.");
+        std::process::exit(1);
+    }
+
+    if !options.input.is_per_day() && puzzles.iter().any(|puzzle| puzzle.day != puzzles[0].day) {
+        eprintln!(
+            "Input {} can only be used with a single day. Select one with --day.",
+            options.input
+        );
+        std::process::exit(2);
+    }
+
+    if options.list {
+        for puzzle in puzzles {
+            println!(
+                "{} Day {}: {} (Part {})",
+                puzzle.year, puzzle.day, puzzle.title, puzzle.part
+            );
+        }
+        return;
+    }
+
+    if options.watch {
+        watch(&options, &puzzles);
+    }
+
+    let exit_code = if options.bench {
+        bench(&options, &puzzles)
+    } else {
+        run(&options, &puzzles)
+    };
+
+    std::process::exit(exit_code);
+}
+
+/// Reads the input of each selected day once and calls `f` for each selected part of that day.
+/// Returns `false` if the input of any day could not be read.
+fn for_each_day(
+    options: &cli::Options,
+    puzzles: &[&Puzzle],
+    mut f: impl FnMut(&[&Puzzle], &str),
+) -> bool {
+    let mut all_read = true;
+
+    for day_puzzles in puzzles.chunk_by(|puzzle, other_puzzle| puzzle.day == other_puzzle.day) {
+        let (day, title) = (day_puzzles[0].day, day_puzzles[0].title);
+
+        match options.input.read(day_puzzles[0].year, day) {
+            Ok(input) => f(day_puzzles, &input),
+            Err(error) => {
+                eprintln!("Day {}: {} - unable to read input: {}", day, title, error);
+                all_read = false;
+            }
+        }
+    }
+
+    all_read
+}
+
+fn run(options: &cli::Options, puzzles: &[&Puzzle]) -> i32 {
+    let answers = if options.verify {
+        match Answers::read(&options.answers_path()) {
+            Ok(answers) => Some(answers),
+            Err(error) => {
+                eprintln!("Unable to read expected answers: {}", error);
+                return 2;
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut verdicts = Vec::new();
+
+    if options.format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+
+    let jobs = options.jobs.unwrap_or(1);
+
+    if allocations::ENABLED && jobs > 1 {
+        eprintln!(
+            "Allocations are only reported when days are solved one at a time, without --jobs."
+        );
+    }
+
+    let start = Instant::now();
+    let outcomes = runner::solve(&options.input, puzzles, jobs, options.timeout);
+    let wall_time = start.elapsed();
+    let mut cpu_time = Duration::ZERO;
+    let mut exit_code = 0;
+    let mut any_unreadable = false;
+
+    let mut allocations_stopped = false;
+
+    for outcome in outcomes {
+        let solved = outcome.solved;
+
+        // The parts of a day share its read and parsed input.
+        if let Some(first) = solved.first() {
+            cpu_time += first.timings.read + first.timings.parse;
+        }
+
+        for Solved {
+            puzzle,
+            answer,
+            timings,
+            allocations,
+        } in solved
+        {
+            let verdict = answers
+                .as_ref()
+                .map(|answers| answers.check(puzzle.day, puzzle.part, &answer));
+            let record = Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: puzzle.part,
+                title: puzzle.title,
+                answer,
+                timings,
+                verdict,
+                allocations,
+            };
+
+            if let Some(verdict) = &record.verdict {
+                verdicts.push(verdict.clone());
+            }
+
+            cpu_time += timings.solve;
+            println!("{}", record.format(options.format));
+        }
+
+        if let Some(error) = outcome.error {
+            eprintln!("Day {}: {} - {}", outcome.day, outcome.title, error);
+
+            if allocations::ENABLED && jobs <= 1 && matches!(error, DayError::TimedOut { .. }) {
+                allocations_stopped = true;
+            }
+
+            any_unreadable |= matches!(error, DayError::Read(_));
+            exit_code = 1;
+        }
+    }
+
+    if allocations_stopped {
+        eprintln!("Allocations are not reported after a day timed out, as it keeps running.");
+    }
+
+    eprintln!(
+        "Total: {:.3}ms wall time, {:.3}ms summed over days, {} {}.",
+        wall_time.as_secs_f64() * 1000_f64,
+        cpu_time.as_secs_f64() * 1000_f64,
+        jobs,
+        if jobs == 1 { "job" } else { "jobs" }
+    );
+
+    if any_unreadable && options.input.is_per_day() {
+        eprintln!("Missing inputs can be downloaded with the fetch command, see --help.");
+    }
+
+    if options.verify {
+        let count = |name| {
+            verdicts
+                .iter()
+                .filter(|verdict| verdict.name() == name)
+                .count()
+        };
+        eprintln!(
+            "Verification: {} passed, {} failed, {} missing.",
+            count("pass"),
+            count("fail"),
+            count("missing")
+        );
+
+        if verdicts.iter().any(|verdict| *verdict != Verdict::Pass) {
+            exit_code = 1;
+        }
+    }
+
+    exit_code
+}
+
+fn fetch(options: &cli::Options) -> i32 {
+    let (settings, year) = (&options.server, options.year());
+    let InputSource::Directory(directory) = &options.input else {
+        unreachable!("The fetch command should save inputs into a directory")
+    };
+    let missing: Vec<u8> = (1..=25)
+        .filter(|&day| options.selects(year, day, 1))
+        .filter(|&day| options.force || !options.input.path(year, day).unwrap().exists())
+        .collect();
+
+    if missing.is_empty() {
         println!(
-            "{} Solved in {:.3}ms.",
-            result,
-            duration.as_secs_f64() * 1000_f64
+            "All selected inputs are already in {}.",
+            input::year_directory(directory, year).display()
+        );
+        return 0;
+    }
+
+    let token = match settings.session_token() {
+        Ok(token) => token,
+        Err(error) => {
+            eprintln!("{}", error);
+            return 2;
+        }
+    };
+    let mut exit_code = 0;
+
+    for day in missing {
+        let path = options.input.path(year, day).unwrap();
+
+        match fetch::fetch_input(settings, &token, directory, year, day, options.force) {
+            Ok(Fetched::Downloaded) => println!("Day {}: saved to {}", day, path.display()),
+            Ok(Fetched::Cached) => println!("Day {}: already in {}", day, path.display()),
+            Err(error) => {
+                eprintln!("Day {}: unable to fetch input: {}", day, error);
+                exit_code = 1;
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn submit(options: &cli::Options) -> i32 {
+    let year = options.year();
+    let (day, part) = (options.days.as_ref().unwrap()[0], options.part.unwrap());
+    let Some(puzzle) = registry::find(year, day, part) else {
+        eprintln!("Day {} (Part {}): no solution to submit.", day, part);
+        return 1;
+    };
+
+    let answer = match options.input.read(year, day) {
+        Ok(input) => match puzzle.solve(&input) {
+            Ok(answer) => answer,
+            Err(error) => {
+                eprintln!("Day {} (Part {}): malformed input: {}", day, part, error);
+                return 1;
+            }
+        },
+        Err(error) => {
+            eprintln!(
+                "Day {} (Part {}): unable to read input: {}",
+                day, part, error
+            );
+            return 1;
+        }
+    };
+
+    if let Answer::Grid(_) = answer {
+        eprintln!(
+            "Day {} (Part {}): the answer is a picture, read it by eye and submit it by hand:\n{}",
+            day, part, answer
+        );
+        return 1;
+    }
+
+    let answer = answer.to_string();
+    let path = options.history_path();
+    let mut history = match History::read(&path) {
+        Ok(history) => history,
+        Err(error) => {
+            eprintln!("Unable to read submissions: {}", error);
+            return 2;
+        }
+    };
+
+    if let Err(reason) = history.check(day, part, &answer, submit::now()) {
+        eprintln!(
+            "Day {} (Part {}): not submitting {}. {}",
+            day, part, answer, reason
+        );
+        return 1;
+    }
+
+    let token = match options.server.session_token() {
+        Ok(token) => token,
+        Err(error) => {
+            eprintln!("{}", error);
+            return 2;
+        }
+    };
+
+    let outcome = match submit::submit(&options.server, &token, year, day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            eprintln!(
+                "Day {} (Part {}): unable to submit {}: {}",
+                day, part, answer, error
+            );
+            return 1;
+        }
+    };
+
+    println!("Day {} (Part {}): {} was {}", day, part, answer, outcome);
+
+    let attempt = Attempt {
+        time: submit::now(),
+        day,
+        part,
+        outcome: outcome.clone(),
+        answer,
+    };
+
+    if let Err(error) = history.record(&path, attempt) {
+        eprintln!("Unable to record the submission: {}", error);
+        return 2;
+    }
+
+    match outcome {
+        Outcome::Correct => 0,
+        _ => 1,
+    }
+}
+
+/// Solves the day whenever its input or examples change, until the process is stopped.
+fn watch(options: &cli::Options, puzzles: &[&Puzzle]) -> ! {
+    let (year, day, title) = (puzzles[0].year, puzzles[0].day, puzzles[0].title);
+    let mut previous: Option<(Snapshot, Run)> = None;
+
+    loop {
+        let files = watch::watched_files(&options.input, year, day);
+        let snapshot = Snapshot::take(&files);
+
+        if previous
+            .as_ref()
+            .map_or(true, |(previous, _)| *previous != snapshot)
+        {
+            let timeout = options.timeout.unwrap_or(watch::DEFAULT_TIMEOUT);
+            let run = Run::solve(&options.input, puzzles, timeout);
+
+            match previous {
+                None => eprintln!(
+                    "Watching {} files of Day {}: {}. Press Ctrl+C to stop.",
+                    files.len(),
+                    day,
+                    title
+                ),
+                Some(_) => eprintln!("\nChange detected, solving Day {} again.", day),
+            }
+
+            for line in run.diff(previous.as_ref().map(|(_, run)| run)) {
+                println!("{}", line);
+            }
+
+            previous = Some((snapshot, run));
+        }
+
+        std::thread::sleep(watch::POLL_INTERVAL);
+    }
+}
+
+fn new_day(options: &cli::Options) -> i32 {
+    let InputSource::Directory(directory) = &options.input else {
+        unreachable!("The new-day command should create inputs in a directory")
+    };
+    let (year, day) = (options.year(), options.days.as_ref().unwrap()[0]);
+    let title = options.title.as_deref().unwrap();
+
+    match scaffold::new_day(Path::new("."), directory, year, day, title) {
+        Ok(changes) => {
+            for change in changes {
+                println!("{}", change);
+            }
+
+            println!(
+                "Paste the example into {}, its answers into {} and run cargo fmt.",
+                input::year_directory(Path::new(EXAMPLES_DIRECTORY), year)
+                    .join(format!("{:02}.txt", day))
+                    .display(),
+                examples::MANIFEST_FILE_NAME
+            );
+            0
+        }
+        Err(error) => {
+            eprintln!("Unable to generate day {} of {}: {}", day, year, error);
+            1
+        }
+    }
+}
+
+fn bench(options: &cli::Options, puzzles: &[&Puzzle]) -> i32 {
+    let settings = &options.bench_settings;
+    let baseline = match &settings.baseline {
+        Some(path) => match Baseline::read(path) {
+            Ok(baseline) => Some(baseline),
+            Err(error) => {
+                eprintln!("Unable to read baseline: {}", error);
+                return 2;
+            }
+        },
+        None => None,
+    };
+
+    let mut medians = Baseline::default();
+    let mut regressions = 0;
+
+    if options.format == Format::Csv {
+        println!("{}", bench::CSV_HEADER);
+    }
+
+    let mut all_solved = true;
+    let all_read = for_each_day(options, puzzles, |day_puzzles, input| {
+        // The parts of a day share its parsed input, as when solving.
+        let (day, title) = (day_puzzles[0].day, day_puzzles[0].title);
+        let model = match runner::catch_panic(|| (day_puzzles[0].parse)(input)) {
+            Ok(Ok(model)) => model,
+            Ok(Err(error)) => {
+                eprintln!("Day {}: {} - {}", day, title, DayError::Parse(error));
+                all_solved = false;
+                return;
+            }
+            Err(message) => {
+                eprintln!("Day {}: {} - {}", day, title, DayError::Panicked(message));
+                all_solved = false;
+                return;
+            }
+        };
+
+        for puzzle in day_puzzles {
+            let measured = runner::catch_panic(|| {
+                bench::measure(settings.warmup, settings.iterations, || {
+                    (puzzle.run)(&*model)
+                })
+            });
+            let stats = match measured {
+                Ok(stats) => stats,
+                Err(message) => {
+                    eprintln!(
+                        "Day {}: {} (Part {}) - {}",
+                        day,
+                        title,
+                        puzzle.part,
+                        DayError::Panicked(message)
+                    );
+                    all_solved = false;
+                    continue;
+                }
+            };
+            let comparison = baseline.as_ref().and_then(|baseline| {
+                let key = (puzzle.year, puzzle.day, puzzle.part);
+                baseline.compare(key, &stats, settings.threshold)
+            });
+            let record = BenchRecord {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: puzzle.part,
+                title: puzzle.title,
+                stats,
+                comparison,
+            };
+
+            if comparison.is_some_and(|comparison| comparison.regression) {
+                regressions += 1;
+            }
+
+            medians.insert(puzzle.year, puzzle.day, puzzle.part, stats.median);
+            println!("{}", record.format(options.format));
+        }
+    });
+
+    if let Some(path) = &settings.save_baseline {
+        if let Err(error) = medians.write(path) {
+            eprintln!("Unable to save baseline: {}", error);
+            return 1;
+        }
+    }
+
+    if baseline.is_some() {
+        eprintln!(
+            "Benchmark: {} regressions above {}%.",
+            regressions, settings.threshold
         );
     }
+
+    if all_read && all_solved && regressions == 0 {
+        0
+    } else {
+        1
+    }
 }
@@ -0,0 +1,278 @@
+use crate::allocations::Allocations;
+use crate::answer::{json_string, Answer};
+use crate::verify::Verdict;
+use std::time::Duration;
+
+pub const CSV_HEADER: &str =
+    "year,day,part,title,kind,answer,read_ms,parse_ms,solve_ms,time_ms,verdict,\
+allocations,allocated_bytes,peak_bytes";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Invalid format: {value}. Expected text, json or csv."
+            )),
+        }
+    }
+}
+
+/// Time spent in each phase of solving a part. The input of a day is read once, so the parts of the
+/// same day share their read time.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+
+    fn to_text(self) -> String {
+        format!(
+            "Solved in {:.3}ms (read {:.3}ms, parse {:.3}ms, solve {:.3}ms).",
+            as_ms(self.total()),
+            as_ms(self.read),
+            as_ms(self.parse),
+            as_ms(self.solve)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    pub answer: Answer,
+    pub timings: Timings,
+    /// Set only when the answer has been checked against the expected answers.
+    pub verdict: Option<Verdict>,
+    /// Set only when allocations are counted, see the `allocations` feature.
+    pub allocations: Option<Allocations>,
+}
+
+impl Record {
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let header = format!(
+            "Day {}: {} (Part {}) answer:",
+            self.day, self.title, self.part
+        );
+        let mut text = match &self.answer {
+            Answer::Grid(_) => format!("{}\n{}\n{}", header, self.answer, self.timings.to_text()),
+            answer => format!("{} {}. {}", header, answer, self.timings.to_text()),
+        };
+
+        if let Some(allocations) = self.allocations {
+            text.push_str(&format!(" {}", allocations.to_text()));
+        }
+
+        match &self.verdict {
+            Some(Verdict::Fail { expected }) if expected.contains('\n') => {
+                text.push_str(&format!(" [fail, expected:]\n{}", expected.trim_end()))
+            }
+            Some(Verdict::Fail { expected }) => {
+                text.push_str(&format!(" [fail, expected: {}]", expected))
+            }
+            Some(verdict) => text.push_str(&format!(" [{}]", verdict.name())),
+            None => (),
+        }
+
+        text
+    }
+
+    pub fn to_json(&self) -> String {
+        let verdict = match &self.verdict {
+            Some(Verdict::Fail { expected }) => {
+                format!(r#","verdict":"fail","expected":{}"#, json_string(expected))
+            }
+            Some(verdict) => format!(r#","verdict":"{}""#, verdict.name()),
+            None => String::new(),
+        };
+        let allocations = self.allocations.map_or(String::new(), |allocations| {
+            format!(
+                r#","allocations":{},"allocated_bytes":{},"peak_bytes":{}"#,
+                allocations.count, allocations.bytes, allocations.peak
+            )
+        });
+
+        format!(
+            r#"{{"year":{},"day":{},"part":{},"title":{},"kind":"{}","answer":{},"read_ms":{:.3},"parse_ms":{:.3},"solve_ms":{:.3},"time_ms":{:.3}{}{}}}"#,
+            self.year,
+            self.day,
+            self.part,
+            json_string(self.title),
+            self.answer.kind(),
+            self.answer.to_json(),
+            as_ms(self.timings.read),
+            as_ms(self.timings.parse),
+            as_ms(self.timings.solve),
+            as_ms(self.timings.total()),
+            verdict,
+            allocations
+        )
+    }
+
+    /// Grid rows are separated by newlines inside a quoted field.
+    pub fn to_csv(&self) -> String {
+        let allocations = self.allocations.map_or(",,".to_owned(), |allocations| {
+            format!(
+                "{},{},{}",
+                allocations.count, allocations.bytes, allocations.peak
+            )
+        });
+
+        format!(
+            "{},{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(self.title),
+            self.answer.kind(),
+            csv_field(&self.answer.to_string()),
+            as_ms(self.timings.read),
+            as_ms(self.timings.parse),
+            as_ms(self.timings.solve),
+            as_ms(self.timings.total()),
+            self.verdict.as_ref().map_or("", Verdict::name),
+            allocations
+        )
+    }
+}
+
+pub(crate) fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000_f64
+}
+
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::*;
+
+    fn record(answer: Answer) -> Record {
+        Record {
+            year: 2021,
+            day: 13,
+            part: 2,
+            title: "Transparent Origami",
+            answer,
+            timings: Timings {
+                read: Duration::from_micros(250),
+                parse: Duration::from_micros(500),
+                solve: Duration::from_micros(750),
+            },
+            verdict: None,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn format_integer_answer() {
+        let record = record(Answer::Integer(17));
+        assert_eq!(
+            record.to_text(),
+            "Day 13: Transparent Origami (Part 2) answer: 17. Solved in 1.500ms (read 0.250ms, parse 0.500ms, solve 0.750ms)."
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2021,"day":13,"part":2,"title":"Transparent Origami","kind":"integer","answer":17,"read_ms":0.250,"parse_ms":0.500,"solve_ms":0.750,"time_ms":1.500}"#
+        );
+        assert_eq!(
+            record.to_csv(),
+            "2021,13,2,Transparent Origami,integer,17,0.250,0.500,0.750,1.500,,,,"
+        );
+    }
+
+    #[test]
+    fn format_grid_answer() {
+        let record = record(Answer::Grid(vec!["█ █".to_owned(), "█\"█".to_owned()]));
+        assert_eq!(
+            record.to_text(),
+            "Day 13: Transparent Origami (Part 2) answer:\n█ █\n█\"█\nSolved in 1.500ms (read 0.250ms, parse 0.500ms, solve 0.750ms)."
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2021,"day":13,"part":2,"title":"Transparent Origami","kind":"grid","answer":["█ █","█\"█"],"read_ms":0.250,"parse_ms":0.500,"solve_ms":0.750,"time_ms":1.500}"#
+        );
+        assert_eq!(
+            record.to_csv(),
+            "2021,13,2,Transparent Origami,grid,\"█ █\n█\"\"█\",0.250,0.500,0.750,1.500,,,,"
+        );
+    }
+
+    #[test]
+    fn format_verified_answer() {
+        let mut record = record(Answer::Integer(17));
+        record.verdict = Some(Verdict::Pass);
+        assert!(record.to_text().ends_with("solve 0.750ms). [pass]"));
+        assert!(record
+            .to_json()
+            .ends_with(r#""time_ms":1.500,"verdict":"pass"}"#));
+        assert!(record.to_csv().ends_with(",1.500,pass,,,"));
+
+        record.verdict = Some(Verdict::Fail {
+            expected: "16".to_owned(),
+        });
+        assert!(record.to_text().ends_with("[fail, expected: 16]"));
+        assert!(record
+            .to_json()
+            .ends_with(r#""verdict":"fail","expected":"16"}"#));
+    }
+
+    #[test]
+    fn format_allocations() {
+        let mut record = record(Answer::Integer(17));
+        record.allocations = Some(Allocations {
+            count: 3,
+            bytes: 2048,
+            peak: 1024,
+        });
+        assert!(record
+            .to_text()
+            .ends_with("solve 0.750ms). Allocated 2.0 KiB in 3 allocations, peak 1.0 KiB."));
+        assert!(record.to_json().ends_with(
+            r#""time_ms":1.500,"allocations":3,"allocated_bytes":2048,"peak_bytes":1024}"#
+        ));
+        assert!(record.to_csv().ends_with(",1.500,,3,2048,1024"));
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,341 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Malformed input, pointing at the line and column (both starting at 1) where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Points at `token`, which should be a slice of `input`. Any other token points at the end of
+    /// the input.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let before = &input[..offset_of(input, token)];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Moves an error reported relative to `token`, e.g. by a `FromStr` implementation parsing a
+    /// single line, to its position in `input`.
+    pub fn within(self, input: &str, token: &str) -> Self {
+        let start = Self::at(input, token, "");
+        let column = match self.line {
+            1 => start.column + self.column - 1,
+            _ => self.column,
+        };
+
+        Self::new(start.line + self.line - 1, column, self.message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn offset_of(input: &str, token: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+
+    if (start..=start + input.len()).contains(&position) {
+        position - start
+    } else {
+        input.len()
+    }
+}
+
+/// Parses `token`, a slice of `input`, describing what was `expected` if it fails.
+pub fn parse_as<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::at(
+            input,
+            token,
+            format!("expected {expected}, found {token:?}"),
+        )
+    })
+}
+
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {delimiter:?} in {text:?}")))
+}
+
+/// Parses `text`, a slice of `input`, as values separated by `delimiter`, ignoring trailing
+/// whitespace.
+pub fn separated<T: FromStr>(
+    input: &str,
+    text: &str,
+    delimiter: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    text.trim_end()
+        .split(delimiter)
+        .map(|token| parse_as(input, token, expected))
+        .collect()
+}
+
+/// Parses comma-separated values, e.g. `3,4,3,1,2`.
+pub fn integers<T: FromStr>(input: &str, text: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+    separated(input, text, ",", expected)
+}
+
+/// Parses two values separated by `delimiter`, e.g. `0,9` with a comma.
+pub fn pair<T: FromStr>(
+    input: &str,
+    text: &str,
+    delimiter: &str,
+    expected: &str,
+) -> Result<(T, T), ParseError> {
+    let (first, second) = split_once(input, text, delimiter)?;
+    Ok((
+        parse_as(input, first, expected)?,
+        parse_as(input, second, expected)?,
+    ))
+}
+
+/// Parses two pairs of comma-separated values joined by an arrow, e.g. `0,9 -> 5,9`.
+pub fn segment<T: FromStr>(
+    input: &str,
+    text: &str,
+    expected: &str,
+) -> Result<[(T, T); 2], ParseError> {
+    let (start, end) = split_once(input, text, " -> ")?;
+    Ok([
+        pair(input, start, ",", expected)?,
+        pair(input, end, ",", expected)?,
+    ])
+}
+
+/// Parses an inclusive range of integers along the axis called `name`, e.g. `x=20..30`.
+pub fn range<T: FromStr>(
+    input: &str,
+    text: &str,
+    name: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let bounds = text
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix('='))
+        .ok_or_else(|| ParseError::at(input, text, format!("expected \"{name}=\" in {text:?}")))?;
+    let (start, end) = pair(input, bounds, "..", "an integer")?;
+    Ok(start..=end)
+}
+
+/// Splits `text` into the blocks of lines separated by blank lines, with either line ending. The
+/// blocks are slices of `text`, so errors found in them keep their position.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+
+    for line in text.lines() {
+        let start = offset_of(text, line);
+
+        match (line.is_empty(), current) {
+            (true, Some((first, last))) => {
+                sections.push(&text[first..last]);
+                current = None;
+            }
+            (true, None) => {}
+            (false, _) => {
+                current = Some((
+                    current.map_or(start, |(first, _)| first),
+                    start + line.len(),
+                ))
+            }
+        }
+    }
+
+    sections.extend(current.map(|(first, last)| &text[first..last]));
+    sections
+}
+
+/// Splits `text`, a slice of `input`, into exactly two sections, the second one containing what
+/// was `expected`.
+pub fn section_pair<'a>(
+    input: &str,
+    text: &'a str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    match sections(text)[..] {
+        [first, second] => Ok((first, second)),
+        [_, _, extra, ..] => Err(ParseError::at(input, extra, "expected the end of input")),
+        _ => Err(ParseError::end_of_input(
+            input,
+            format!("expected a blank line followed by {expected}"),
+        )),
+    }
+}
+
+/// Splits `text`, a slice of `input`, into lines of equal width.
+pub fn grid_lines<'a>(input: &str, text: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let width = match lines.first() {
+        Some(line) if !line.is_empty() => line.chars().count(),
+        _ => return Err(ParseError::at(input, text, "expected a grid")),
+    };
+
+    match lines.iter().find(|line| line.chars().count() != width) {
+        Some(line) => Err(ParseError::at(
+            input,
+            line,
+            format!("expected {} columns, found {}", width, line.chars().count()),
+        )),
+        None => Ok(lines),
+    }
+}
+
+/// Checks that `line`, a slice of `input`, consists only of the `allowed` characters.
+pub fn only_chars<'a>(
+    input: &str,
+    line: &'a str,
+    allowed: &str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    match line.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+        Some((index, c)) => Err(ParseError::at(
+            input,
+            &line[index..],
+            format!("expected {expected}, found {c:?}"),
+        )),
+        None => Ok(line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+
+    #[test]
+    fn locate_error_in_input() {
+        let input = "12\n3x5\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(input, &line[1..], "expected a digit");
+        assert_eq!(error, ParseError::new(2, 2, "expected a digit"));
+        assert_eq!(error.to_string(), "line 2, column 2: expected a digit");
+        assert_eq!(ParseError::end_of_input(input, "").line, 3);
+        assert_eq!(ParseError::at(input, "elsewhere", "").line, 3);
+    }
+
+    #[test]
+    fn move_error_from_token_to_input() {
+        let input = "1,2\n3,x";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new(1, 3, "expected a number").within(input, line);
+        assert_eq!(error, ParseError::new(2, 3, "expected a number"));
+    }
+
+    #[test]
+    fn parse_tokens_of_input() {
+        let input = "forward 5\ndown x";
+        let line = input.lines().nth(1).unwrap();
+        let (_, value) = split_once(input, line, " ").unwrap();
+        assert_eq!(
+            parse_as::<u8>(input, value, "a number"),
+            Err(ParseError::new(2, 6, "expected a number, found \"x\""))
+        );
+        assert_eq!(
+            split_once(input, line, " -> ").unwrap_err().to_string(),
+            "line 2, column 1: expected \" -> \" in \"down x\""
+        );
+        let input = "21a9";
+        assert_eq!(
+            only_chars(input, input, "0123456789", "a digit"),
+            Err(ParseError::new(1, 3, "expected a digit, found 'a'"))
+        );
+        assert_eq!(only_chars(input, "0110", "01", "a bit"), Ok("0110"));
+
+        let input = "123\n45\n";
+        assert_eq!(
+            grid_lines(input, input),
+            Err(ParseError::new(2, 1, "expected 3 columns, found 2"))
+        );
+        assert_eq!(grid_lines(input, &input[..3]), Ok(vec!["123"]));
+        assert!(grid_lines(input, "").is_err());
+    }
+
+    #[test]
+    fn parse_recurring_formats() {
+        let input = "3,4,3,1,2\n";
+        assert_eq!(integers(input, input, "a timer"), Ok(vec![3u8, 4, 3, 1, 2]));
+        assert_eq!(
+            separated::<u8>("1 2 x", "1 2 x", " ", "a number"),
+            Err(ParseError::new(1, 5, "expected a number, found \"x\""))
+        );
+
+        let input = "0,9 -> 5,9\n8,0 -> 0,y";
+        let (first, second) = input.split_once('\n').unwrap();
+        assert_eq!(
+            segment(input, first, "a coordinate"),
+            Ok([(0u16, 9), (5, 9)])
+        );
+        assert_eq!(
+            segment::<u16>(input, second, "a coordinate"),
+            Err(ParseError::new(2, 10, "expected a coordinate, found \"y\""))
+        );
+        assert_eq!(pair(input, "5,9", ",", "a coordinate"), Ok((5u16, 9)));
+
+        let input = "target area: x=20..30, y=-10..-5";
+        let (x, y) = split_once(input, &input[13..], ", ").unwrap();
+        assert_eq!(range(input, x, "x"), Ok(20..=30));
+        assert_eq!(range(input, y, "y"), Ok(-10..=-5));
+        assert_eq!(
+            range::<i32>(input, y, "z"),
+            Err(ParseError::new(1, 24, "expected \"z=\" in \"y=-10..-5\""))
+        );
+    }
+
+    #[test]
+    fn split_input_into_sections() {
+        let input = "NNCB\n\nCH -> B\nHH -> N\n";
+        assert_eq!(sections(input), ["NNCB", "CH -> B\nHH -> N"]);
+        let (_, rules) = section_pair(input, input, "rules").unwrap();
+        assert_eq!(ParseError::at(input, rules, "").line, 3);
+        assert_eq!(
+            section_pair(input, "NNCB\n", "rules"),
+            Err(ParseError::new(
+                5,
+                1,
+                "expected a blank line followed by rules"
+            ))
+        );
+
+        let input = "1\n\n2\n\n\n3\n";
+        assert_eq!(sections(input), ["1", "2", "3"]);
+        assert_eq!(sections("1\r\n2\r\n\r\n3\r\n"), ["1\r\n2", "3"]);
+        assert_eq!(
+            section_pair(input, input, "rules"),
+            Err(ParseError::new(6, 1, "expected the end of input"))
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::year_2021;
+use std::any::Any;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    /// Parses the input into the model of the day, which is the same for both of its parts.
+    pub parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    /// Solves the part from a model returned by `parse` of a puzzle of the same day.
+    pub run: fn(&dyn Any) -> Answer,
+}
+
+impl Puzzle {
+    pub const fn part_1<S: Solution>(year: u16) -> Self {
+        Self {
+            year,
+            day: S::DAY,
+            part: 1,
+            title: S::TITLE,
+            parse: parse::<S>,
+            run: |model| S::part_1(downcast::<S>(model)),
+        }
+    }
+
+    pub const fn part_2<S: Solution>(year: u16) -> Self {
+        assert!(S::PARTS == 2, "The puzzle should have a second part");
+
+        Self {
+            year,
+            day: S::DAY,
+            part: 2,
+            title: S::TITLE,
+            parse: parse::<S>,
+            run: |model| S::part_2(downcast::<S>(model)),
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Result<Answer, ParseError> {
+        (self.parse)(input).map(|model| (self.run)(&*model))
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError> {
+    S::parse(input).map(|model| Box::new(model) as Box<dyn Any>)
+}
+
+fn downcast<S: Solution>(model: &dyn Any) -> &S::Input {
+    model
+        .downcast_ref()
+        .expect("The model should be parsed by a puzzle of the same day")
+}
+
+/// Puzzles of every event in order of year, each ordered by day and part. New events are added
+/// by the new-day command.
+pub static EVENTS: &[&[Puzzle]] = &[year_2021::PUZZLES];
+
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    EVENTS.iter().flat_map(|puzzles| puzzles.iter())
+}
+
+pub fn years() -> impl Iterator<Item = u16> {
+    EVENTS.iter().map(|puzzles| puzzles[0].year)
+}
+
+/// The year selected when none is given.
+pub fn latest_year() -> u16 {
+    years()
+        .max()
+        .expect("At least one event should be registered")
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Puzzle> {
+    puzzles().find(|puzzle| (puzzle.year, puzzle.day, puzzle.part) == (year, day, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::*;
+
+    #[test]
+    fn puzzles_are_ordered_by_year_day_and_part() {
+        let puzzles: Vec<&Puzzle> = puzzles().collect();
+        assert!(puzzles.windows(2).all(|pair| {
+            (pair[0].year, pair[0].day, pair[0].part) < (pair[1].year, pair[1].day, pair[1].part)
+        }));
+
+        assert!(puzzles.iter().all(|puzzle| (1..=25).contains(&puzzle.day)));
+        assert!(puzzles
+            .iter()
+            .all(|puzzle| find(puzzle.year, puzzle.day, 1).is_some()));
+        assert!(EVENTS.iter().all(|puzzles| !puzzles.is_empty()));
+
+        let days_of_2021 = puzzles.iter().filter(|puzzle| puzzle.year == 2021);
+        assert_eq!(days_of_2021.filter(|puzzle| puzzle.part == 1).count(), 25);
+        assert_eq!(
+            find(2021, 25, 1).map(|puzzle| puzzle.title),
+            Some("Sea Cucumber")
+        );
+        assert!(find(2021, 25, 2).is_none());
+    }
+
+    #[test]
+    fn solve_or_reject_empty_input() {
+        for puzzle in puzzles() {
+            if let Ok(model) = (puzzle.parse)("") {
+                (puzzle.run)(&*model);
+            }
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use crate::allocations::{self, Allocations};
+use crate::answer::Answer;
+use crate::input::InputSource;
+use crate::output::Timings;
+use crate::parse::ParseError;
+use crate::registry::Puzzle;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+pub struct Solved<'a> {
+    pub puzzle: &'a Puzzle,
+    pub answer: Answer,
+    pub timings: Timings,
+    /// Allocations made while solving the part, counted only when days are solved one at a time.
+    pub allocations: Option<Allocations>,
+}
+
+/// Why the parts of a day could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayError {
+    Read(String),
+    Parse(ParseError),
+    /// Parsing the input, or solving the part if there is one, took longer than `limit`.
+    TimedOut {
+        part: Option<u8>,
+        limit: Duration,
+    },
+    /// The solver panicked, with this message.
+    Panicked(String),
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::Read(error) => write!(f, "unable to read input: {}", error),
+            DayError::Parse(error) => write!(f, "malformed input: {}", error),
+            DayError::TimedOut {
+                part: Some(part),
+                limit,
+            } => write!(f, "part {} timed out after {:?}", part, limit),
+            DayError::TimedOut { part: None, limit } => {
+                write!(f, "parsing the input timed out after {:?}", limit)
+            }
+            DayError::Panicked(message) => write!(f, "solver panicked: {}", message),
+        }
+    }
+}
+
+pub struct DayOutcome<'a> {
+    pub day: u8,
+    pub title: &'static str,
+    /// Parts solved before an error, if any, stopped the day.
+    pub solved: Vec<Solved<'a>>,
+    /// Why the remaining parts of the day were not solved.
+    pub error: Option<DayError>,
+}
+
+/// Solves the selected parts, with up to `jobs` days solved at the same time. The parts of a day
+/// share its input, which is read and parsed once, so they are always solved one after another on
+/// the same thread.
+/// Outcomes are returned in the order of `puzzles`, regardless of which day finished first.
+/// Allocations are only counted with a single job, as other days would be counted as well.
+///
+/// With a `timeout`, parsing the input and solving each part must each finish within it. A day
+/// that does not is reported as timed out, while its solver is left running in the background
+/// until the process exits, since a thread cannot be stopped from the outside. Allocations are
+/// no longer counted from then on, as that solver keeps allocating.
+pub fn solve<'a>(
+    source: &InputSource,
+    puzzles: &[&'a Puzzle],
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<DayOutcome<'a>> {
+    let days: Vec<&[&Puzzle]> = puzzles
+        .chunk_by(|puzzle, other_puzzle| {
+            (puzzle.year, puzzle.day) == (other_puzzle.year, other_puzzle.day)
+        })
+        .collect();
+    let next_day = AtomicUsize::new(0);
+    let count_allocations = AtomicBool::new(allocations::ENABLED && jobs <= 1);
+
+    let mut outcomes: Vec<(usize, DayOutcome)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outcomes = Vec::new();
+
+                    loop {
+                        let index = next_day.fetch_add(1, Ordering::Relaxed);
+
+                        match days.get(index) {
+                            Some(day_puzzles) => outcomes.push((
+                                index,
+                                solve_day(source, day_puzzles, &count_allocations, timeout),
+                            )),
+                            None => break outcomes,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+
+    outcomes.sort_unstable_by_key(|(index, _)| *index);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn solve_day<'a>(
+    source: &InputSource,
+    day_puzzles: &[&'a Puzzle],
+    count_allocations: &AtomicBool,
+    timeout: Option<Duration>,
+) -> DayOutcome<'a> {
+    let (year, day, title) = (
+        day_puzzles[0].year,
+        day_puzzles[0].day,
+        day_puzzles[0].title,
+    );
+    let mut outcome = DayOutcome {
+        day,
+        title,
+        solved: Vec::new(),
+        error: None,
+    };
+    let start = Instant::now();
+    let input = match source.read(year, day) {
+        Ok(input) => input,
+        Err(error) => {
+            outcome.error = Some(DayError::Read(error.to_string()));
+            return outcome;
+        }
+    };
+    let read = start.elapsed();
+
+    let parse_fn = day_puzzles[0].parse;
+    let run_fns: Vec<RunFn> = day_puzzles.iter().map(|puzzle| puzzle.run).collect();
+    let counting = count_allocations.load(Ordering::Relaxed);
+    let (steps, timed_out) = match timeout {
+        Some(limit) => match solve_in_background(input, parse_fn, run_fns, counting, limit) {
+            Ok(steps) => (steps, None),
+            Err(finished_steps) => (finished_steps, Some(limit)),
+        },
+        None => {
+            let mut steps = Vec::new();
+            solve_catching_panics(&input, parse_fn, &run_fns, counting, |step| {
+                steps.push(step)
+            });
+            (steps, None)
+        }
+    };
+
+    let mut parse = Duration::ZERO;
+    let mut parsed = false;
+
+    for step in steps {
+        match step {
+            Step::Parsed(Ok(duration)) => (parse, parsed) = (duration, true),
+            Step::Parsed(Err(error)) => outcome.error = Some(DayError::Parse(error)),
+            Step::Solved(answer, solve, allocations) => outcome.solved.push(Solved {
+                puzzle: day_puzzles[outcome.solved.len()],
+                answer,
+                timings: Timings { read, parse, solve },
+                allocations,
+            }),
+            Step::Panicked(message) => outcome.error = Some(DayError::Panicked(message)),
+        }
+    }
+
+    if let Some(limit) = timed_out {
+        count_allocations.store(false, Ordering::Relaxed);
+        outcome.error = Some(DayError::TimedOut {
+            part: parsed.then(|| day_puzzles[outcome.solved.len()].part),
+            limit,
+        });
+    }
+
+    outcome
+}
+
+type ParseFn = fn(&str) -> Result<Box<dyn Any>, ParseError>;
+type RunFn = fn(&dyn Any) -> Answer;
+
+/// Progress of solving a day: its input is parsed, then each part is solved in turn, unless
+/// parsing or solving panics.
+enum Step {
+    Parsed(Result<Duration, ParseError>),
+    Solved(Answer, Duration, Option<Allocations>),
+    Panicked(String),
+}
+
+/// Parses the input and solves each part, reporting every step as soon as it is finished.
+fn solve_input(
+    input: &str,
+    parse_fn: ParseFn,
+    run_fns: &[RunFn],
+    count_allocations: bool,
+    mut report: impl FnMut(Step),
+) {
+    let start = Instant::now();
+    let model = match parse_fn(input) {
+        Ok(model) => model,
+        Err(error) => return report(Step::Parsed(Err(error))),
+    };
+    report(Step::Parsed(Ok(start.elapsed())));
+
+    for run_fn in run_fns {
+        let start = Instant::now();
+        let (answer, allocations) = match count_allocations {
+            true => allocations::measure(|| run_fn(&*model)),
+            false => (run_fn(&*model), None),
+        };
+        report(Step::Solved(answer, start.elapsed(), allocations));
+    }
+}
+
+/// Calls `f`, returning the message of its panic as an error instead of unwinding.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "unknown cause".to_owned(),
+        }
+    })
+}
+
+/// Same as `solve_input`, reporting a panic as the last step instead of unwinding.
+fn solve_catching_panics(
+    input: &str,
+    parse_fn: ParseFn,
+    run_fns: &[RunFn],
+    count_allocations: bool,
+    mut report: impl FnMut(Step),
+) {
+    let solved =
+        catch_panic(|| solve_input(input, parse_fn, run_fns, count_allocations, &mut report));
+
+    if let Err(message) = solved {
+        report(Step::Panicked(message));
+    }
+}
+
+/// Runs `solve_catching_panics` on a thread of its own, waiting at most `limit` for each step.
+/// Returns the finished steps as an error if one takes longer.
+fn solve_in_background(
+    input: String,
+    parse_fn: ParseFn,
+    run_fns: Vec<RunFn>,
+    count_allocations: bool,
+    limit: Duration,
+) -> Result<Vec<Step>, Vec<Step>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        solve_catching_panics(&input, parse_fn, &run_fns, count_allocations, |step| {
+            let _ = sender.send(step);
+        })
+    });
+    let mut steps = Vec::new();
+
+    loop {
+        match receiver.recv_timeout(limit) {
+            Ok(step) => steps.push(step),
+            Err(RecvTimeoutError::Timeout) => return Err(steps),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::*;
+    use crate::year_2021::PUZZLES;
+
+    fn answers(outcomes: &[DayOutcome]) -> Vec<(u8, u8, Answer)> {
+        outcomes
+            .iter()
+            .flat_map(|outcome| &outcome.solved)
+            .map(|solved| (solved.puzzle.day, solved.puzzle.part, solved.answer.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn solve_days_in_parallel_in_stable_order() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let puzzles: Vec<&Puzzle> = PUZZLES.iter().filter(|puzzle| puzzle.day <= 6).collect();
+
+        let sequential = solve(&source, &puzzles, 1, None);
+        let parallel = solve(&source, &puzzles, 4, None);
+        assert_eq!(parallel.len(), 6);
+        assert!(parallel.iter().all(|outcome| outcome.error.is_none()));
+        assert_eq!(answers(&sequential), answers(&parallel));
+        assert_eq!(answers(&parallel)[0], (1, 1, Answer::Integer(7)));
+        assert_eq!(answers(&parallel)[11], (6, 2, Answer::Integer(26984457539)));
+    }
+
+    #[test]
+    fn report_unreadable_input_of_a_day() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let puzzles: Vec<&Puzzle> = PUZZLES.iter().filter(|puzzle| puzzle.day >= 24).collect();
+
+        let outcomes = solve(&source, &puzzles, 2, None);
+        assert_eq!(outcomes.len(), 2);
+        assert!(matches!(outcomes[0].error, Some(DayError::Read(_))));
+        assert!(outcomes[0].solved.is_empty());
+        assert_eq!(outcomes[1].day, 25);
+        assert_eq!(outcomes[1].error, None);
+    }
+
+    #[test]
+    fn report_day_that_does_not_finish_in_time() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        // The second part takes longer than the limit, but does finish in the background.
+        let slow = Puzzle {
+            run: |model| {
+                std::thread::sleep(Duration::from_millis(500));
+                (PUZZLES[1].run)(model)
+            },
+            ..PUZZLES[1]
+        };
+        let puzzles = [&PUZZLES[0], &slow];
+
+        let outcomes = solve(&source, &puzzles, 1, Some(Duration::from_millis(100)));
+        assert_eq!(answers(&outcomes), [(1, 1, Answer::Integer(7))]);
+        assert_eq!(
+            outcomes[0].error,
+            Some(DayError::TimedOut {
+                part: Some(2),
+                limit: Duration::from_millis(100)
+            })
+        );
+        assert_eq!(
+            outcomes[0].error.as_ref().unwrap().to_string(),
+            "part 2 timed out after 100ms"
+        );
+
+        let outcomes = solve(&source, &puzzles, 1, Some(Duration::from_secs(10)));
+        assert_eq!(
+            answers(&outcomes),
+            [(1, 1, Answer::Integer(7)), (1, 2, Answer::Integer(5))]
+        );
+        assert_eq!(outcomes[0].error, None);
+    }
+
+    #[test]
+    fn return_message_of_panic() {
+        assert_eq!(catch_panic(|| 7), Ok(7));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("no solution") }),
+            Err("no solution".to_owned())
+        );
+        let day = 26;
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("no day {}", day) }),
+            Err("no day 26".to_owned())
+        );
+    }
+
+    #[test]
+    fn report_panic_of_a_day_and_go_on() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let panicking = Puzzle {
+            run: |_| panic!("no solution"),
+            ..PUZZLES[5]
+        };
+        let puzzles = [&PUZZLES[0], &PUZZLES[4], &panicking, &PUZZLES[6]];
+
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let outcomes = solve(&source, &puzzles, 1, timeout);
+            assert_eq!(
+                outcomes[1].error,
+                Some(DayError::Panicked("no solution".to_owned()))
+            );
+            assert_eq!(outcomes[1].solved.len(), 1);
+            assert!(outcomes[0].error.is_none() && outcomes[2].error.is_none());
+        }
+    }
+
+    #[test]
+    fn report_malformed_input_of_a_day() {
+        let source = InputSource::File("./test_input/2021/01.txt".into());
+        let puzzles: Vec<&Puzzle> = PUZZLES.iter().filter(|puzzle| puzzle.day == 2).collect();
+
+        let outcomes = solve(&source, &puzzles, 1, None);
+        let error = outcomes[0].error.as_ref().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "malformed input: line 1, column 1: expected \" \" in \"199\""
+        );
+    }
+}
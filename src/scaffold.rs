@@ -0,0 +1,537 @@
+use crate::examples::MANIFEST_FILE_NAME;
+use crate::input::{self, EXAMPLES_DIRECTORY};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The first event took place in 2015.
+pub const FIRST_YEAR: u16 = 2015;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Created(PathBuf),
+    Updated(PathBuf),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Created(path) => write!(f, "created {}", path.display()),
+            Change::Updated(path) => write!(f, "updated {}", path.display()),
+        }
+    }
+}
+
+/// Module of a day, e.g. `day_07_the_treachery_of_whales` for "The Treachery of Whales".
+pub fn module_name(day: u8, title: &str) -> String {
+    let words: Vec<String> = title_words(title).map(|word| word.to_lowercase()).collect();
+    format!("day_{:02}_{}", day, words.join("_"))
+}
+
+/// Type implementing `Solution` for a day, e.g. `TheTreacheryOfWhales`. Names that would not be
+/// identifiers, starting with a digit or being the keyword `Self`, start with `Day` instead.
+pub fn type_name(title: &str) -> String {
+    let name: String = title_words(title)
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            first.to_string() + &chars.as_str().to_lowercase()
+        })
+        .collect();
+
+    match name.starts_with(|c: char| c.is_ascii_alphabetic()) && name != "Self" {
+        true => name,
+        false => format!("Day{name}"),
+    }
+}
+
+fn title_words(title: &str) -> impl Iterator<Item = &str> {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+/// Generates the modules of a new day in the source tree under `root`, registers its solution and
+/// creates an empty input in `input_directory` and an empty example listed in the manifest of the
+/// year. A new year gets its own module, registered in `lib.rs` and `registry.rs`.
+pub fn new_day(
+    root: &Path,
+    input_directory: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<Change>, String> {
+    let title = title.trim();
+
+    if !root.join("src").join("registry.rs").exists() {
+        return Err(format!(
+            "{} is not the root of the repository.",
+            root.display()
+        ));
+    }
+
+    if !title.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Err(format!(
+            "Invalid title: {title:?}. Expected the name of the puzzle."
+        ));
+    }
+
+    let (module, type_name) = (module_name(day, title), type_name(title));
+    let year_module = format!("year_{year}");
+    let year_directory = root.join("src").join(&year_module);
+    let prefix = format!("day_{:02}_", day);
+
+    if let Ok(entries) = std::fs::read_dir(&year_directory) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                return Err(format!(
+                    "Day {} of {} already exists: {}",
+                    day,
+                    year,
+                    entry.path().display()
+                ));
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+    let day_directory = year_directory.join(&module);
+    let path_of_day = format!("crate::{year_module}::{module}");
+
+    create(
+        &mut changes,
+        &day_directory.join("mod.rs"),
+        &day_module(day, title, &type_name),
+    )?;
+
+    for part in 1..=2 {
+        create(
+            &mut changes,
+            &day_directory.join(format!("part_{part}.rs")),
+            &part_module(&path_of_day, &year_module, day, part),
+        )?;
+    }
+
+    let year_mod_rs = year_directory.join("mod.rs");
+
+    if year_mod_rs.exists() {
+        update(&mut changes, &year_mod_rs, |content| {
+            add_day_to_year_module(content, day, &module, &type_name)
+        })?;
+    } else {
+        create(
+            &mut changes,
+            &year_mod_rs,
+            &year_module_source(year, &module, &type_name),
+        )?;
+        update(&mut changes, &root.join("src").join("lib.rs"), |content| {
+            add_year_to_lib(content, year)
+        })?;
+        update(
+            &mut changes,
+            &root.join("src").join("registry.rs"),
+            |content| add_year_to_registry(content, year),
+        )?;
+    }
+
+    let examples_directory = root.join(EXAMPLES_DIRECTORY.trim_start_matches("./"));
+    let examples = input::year_directory(&examples_directory, year);
+    let example = format!("{:02}.txt", day);
+    let manifest = examples.join(MANIFEST_FILE_NAME);
+
+    if !examples.join(&example).exists() {
+        create(&mut changes, &examples.join(&example), "")?;
+    }
+
+    if !manifest.exists() {
+        create(&mut changes, &manifest, MANIFEST_HEADER)?;
+    }
+
+    update(&mut changes, &manifest, |content| {
+        Ok(add_example_to_manifest(content, day, &example))
+    })?;
+
+    let input = input::year_directory(input_directory, year).join(&example);
+
+    if !input.exists() {
+        create(&mut changes, &input, "")?;
+    }
+
+    Ok(changes)
+}
+
+fn create(changes: &mut Vec<Change>, path: &Path, content: &str) -> Result<(), String> {
+    std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| std::fs::write(path, content))
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+    changes.push(Change::Created(path.to_owned()));
+    Ok(())
+}
+
+fn update(
+    changes: &mut Vec<Change>,
+    path: &Path,
+    edit: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    let error = |error: String| format!("{}: {}", path.display(), error);
+    let content = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    let content = edit(&content).map_err(error)?;
+    std::fs::write(path, content).map_err(|e| error(e.to_string()))?;
+
+    if !changes.contains(&Change::Created(path.to_owned())) {
+        changes.push(Change::Updated(path.to_owned()));
+    }
+
+    Ok(())
+}
+
+/// Inserts `line` among the lines matching `is_entry`, before the first one that sorts after it.
+/// Without any matching line, it goes after the last line matching `is_anchor`.
+fn insert_line(
+    content: &str,
+    line: &str,
+    is_entry: impl Fn(&str) -> bool,
+    is_anchor: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let index = match lines.iter().position(|&entry| is_entry(entry)) {
+        Some(_) => lines
+            .iter()
+            .position(|&entry| is_entry(entry) && entry > line)
+            .unwrap_or_else(|| lines.iter().rposition(|&entry| is_entry(entry)).unwrap() + 1),
+        None => lines.iter().rposition(|&entry| is_anchor(entry))? + 1,
+    };
+
+    let mut lines: Vec<String> = lines.into_iter().map(str::to_owned).collect();
+    lines.insert(index, line.to_owned());
+    Some(lines.join("\n") + "\n")
+}
+
+fn add_day_to_year_module(
+    content: &str,
+    day: u8,
+    module: &str,
+    type_name: &str,
+) -> Result<String, String> {
+    let missing = |what: &str| format!("unable to find {what}");
+    let content = insert_line(
+        content,
+        &format!("use {module}::{type_name};"),
+        |line| line.starts_with("use day_"),
+        |line| line.starts_with("use "),
+    )
+    .ok_or_else(|| missing("the imports"))?;
+    let content = insert_line(
+        &content,
+        &format!("pub mod {module};"),
+        |line| line.starts_with("pub mod day_"),
+        |line| line.starts_with("use "),
+    )
+    .ok_or_else(|| missing("the modules"))?;
+
+    // Entries of the list only name the type of their day, so days are looked up in the imports.
+    let day_of_type = |entry: &str| {
+        let entry_type = entry.split(['<', '>']).nth(1)?;
+        content.lines().find_map(|line| {
+            let (path, imported_type) = line.strip_prefix("use day_")?.split_once("::")?;
+            match imported_type.trim_end_matches(';') == entry_type {
+                true => path.get(..2)?.parse::<u8>().ok(),
+                false => None,
+            }
+        })
+    };
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const PUZZLES: &[Puzzle] = &["))
+        .ok_or_else(|| missing("the list of puzzles"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| missing("the end of the list of puzzles"))?;
+    let index = (start + 1..end)
+        .find(|&index| day_of_type(lines[index]).is_some_and(|other_day| other_day > day))
+        .unwrap_or(end);
+
+    let entries = [
+        format!("    Puzzle::part_1::<{type_name}>(YEAR),"),
+        format!("    Puzzle::part_2::<{type_name}>(YEAR),"),
+    ];
+    lines.splice(index..index, entries.iter().map(String::as_str));
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_year_to_lib(content: &str, year: u16) -> Result<String, String> {
+    insert_line(
+        content,
+        &format!("pub mod year_{year};"),
+        |line| line.starts_with("pub mod year_"),
+        |line| line.starts_with("pub mod "),
+    )
+    .ok_or_else(|| "unable to find the modules".to_owned())
+}
+
+fn add_year_to_registry(content: &str, year: u16) -> Result<String, String> {
+    let content = insert_line(
+        content,
+        &format!("use crate::year_{year};"),
+        |line| line.starts_with("use crate::year_"),
+        |line| line.starts_with("use "),
+    )
+    .ok_or_else(|| "unable to find the imports".to_owned())?;
+
+    const EVENTS: &str = "pub static EVENTS: &[&[Puzzle]] = &[";
+    let start = content
+        .find(EVENTS)
+        .ok_or_else(|| "unable to find the list of events".to_owned())?;
+    let end = start
+        + content[start..]
+            .find("];")
+            .ok_or_else(|| "unable to find the end of the list of events".to_owned())?
+        + 2;
+
+    let mut years: Vec<&str> = content[start..end]
+        .split("year_")
+        .skip(1)
+        .filter_map(|rest| rest.get(..4))
+        .collect();
+    let year = year.to_string();
+    years.push(&year);
+    years.sort_unstable();
+    years.dedup();
+
+    let events: Vec<String> = years
+        .iter()
+        .map(|year| format!("year_{year}::PUZZLES"))
+        .collect();
+    Ok(format!(
+        "{}{}{}];{}",
+        &content[..start],
+        EVENTS,
+        events.join(", "),
+        &content[end..]
+    ))
+}
+
+fn add_example_to_manifest(content: &str, day: u8, example: &str) -> String {
+    let is_example = |line: &str| !line.trim().is_empty() && !line.starts_with('#');
+    let mut content = content.to_owned();
+
+    for part in 1..=2 {
+        content = insert_line(
+            &content,
+            &format!("{:02} {} {} 0", day, part, example),
+            is_example,
+            |line| line.starts_with('#'),
+        )
+        .unwrap_or_else(|| format!("{content}{:02} {} {} 0\n", day, part, example));
+    }
+
+    content
+}
+
+const MANIFEST_HEADER: &str = "\
+# Example inputs in this directory and the expected answer of each part, one per line:
+# day, part, file and answer. Answers are integers or strings in double quotes, where \\n
+# separates the rows of a grid. The examples are run by `cargo test`.
+";
+
+fn year_module_source(year: u16, module: &str, type_name: &str) -> String {
+    format!(
+        "\
+use crate::registry::Puzzle;
+use {module}::{type_name};
+
+pub mod {module};
+
+pub const YEAR: u16 = {year};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::part_1::<{type_name}>(YEAR),
+    Puzzle::part_2::<{type_name}>(YEAR),
+];
+
+/// Reads an example input of this event, e.g. `01.txt` or `22_2.txt`.
+#[cfg(test)]
+pub(crate) fn read_example(file_name: &str) -> std::io::Result<String> {{
+    crate::input::read_example(YEAR, file_name)
+}}
+"
+    )
+}
+
+fn day_module(day: u8, title: &str, type_name: &str) -> String {
+    format!(
+        "\
+use crate::answer::Answer;
+use crate::parse::{{parse_as, ParseError}};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {{
+    input
+        .lines()
+        .map(|line| parse_as(input, line.trim_end(), \"a number\"))
+        .collect()
+}}
+
+pub struct {type_name};
+
+impl Solution for {type_name} {{
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title:?};
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse(input)
+    }}
+
+    fn part_1(numbers: &Self::Input) -> Answer {{
+        part_1::solve(numbers).into()
+    }}
+
+    fn part_2(numbers: &Self::Input) -> Answer {{
+        part_2::solve(numbers).into()
+    }}
+}}
+"
+    )
+}
+
+fn part_module(path_of_day: &str, year_module: &str, day: u8, part: u8) -> String {
+    format!(
+        "\
+pub fn solve(numbers: &[i64]) -> i64 {{
+    numbers.iter().sum()
+}}
+
+#[cfg(test)]
+mod tests {{
+    use {path_of_day}::parse;
+    use {path_of_day}::part_{part}::*;
+    use crate::{year_module}::read_example;
+
+    #[test]
+    fn solve_with_test_data() {{
+        let numbers = parse(&read_example(\"{day:02}.txt\").unwrap()).unwrap();
+        let result = solve(&numbers);
+        assert_eq!(result, 0);
+    }}
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    const YEAR_MODULE: &str = "\
+use crate::registry::Puzzle;
+use day_01_sonar_sweep::SonarSweep;
+use day_03_binary_diagnostic::BinaryDiagnostic;
+
+pub mod day_01_sonar_sweep;
+pub mod day_03_binary_diagnostic;
+
+pub const YEAR: u16 = 2021;
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::part_1::<SonarSweep>(YEAR),
+    Puzzle::part_2::<SonarSweep>(YEAR),
+    Puzzle::part_1::<BinaryDiagnostic>(YEAR),
+    Puzzle::part_2::<BinaryDiagnostic>(YEAR),
+];
+";
+
+    #[test]
+    fn name_modules_and_types_after_the_title() {
+        assert_eq!(
+            module_name(7, "The Treachery of Whales"),
+            "day_07_the_treachery_of_whales"
+        );
+        assert_eq!(type_name("The Treachery of Whales"), "TheTreacheryOfWhales");
+        assert_eq!(module_name(2, "Dive!"), "day_02_dive");
+        assert_eq!(type_name("Dive!"), "Dive");
+        assert_eq!(module_name(3, "3D Maze"), "day_03_3d_maze");
+        assert_eq!(type_name("3D Maze"), "Day3dMaze");
+        assert_eq!(type_name("Self"), "DaySelf");
+    }
+
+    #[test]
+    fn register_day_in_order() {
+        let content = add_day_to_year_module(YEAR_MODULE, 2, "day_02_dive", "Dive").unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines[2], "use day_02_dive::Dive;");
+        assert_eq!(lines[6], "pub mod day_02_dive;");
+        assert_eq!(lines[14], "    Puzzle::part_1::<Dive>(YEAR),");
+        assert_eq!(lines[15], "    Puzzle::part_2::<Dive>(YEAR),");
+        assert_eq!(lines[16], "    Puzzle::part_1::<BinaryDiagnostic>(YEAR),");
+
+        let content = add_day_to_year_module(YEAR_MODULE, 4, "day_04_giant_squid", "GiantSquid");
+        assert!(content
+            .unwrap()
+            .ends_with("    Puzzle::part_2::<GiantSquid>(YEAR),\n];\n"));
+    }
+
+    #[test]
+    fn register_new_year() {
+        let lib = "pub mod verify;\npub mod year_2021;\n";
+        assert_eq!(
+            add_year_to_lib(lib, 2015),
+            Ok("pub mod verify;\npub mod year_2015;\npub mod year_2021;\n".to_owned())
+        );
+
+        let registry = "use crate::solution::Solution;\nuse crate::year_2021;\n\n\
+            pub static EVENTS: &[&[Puzzle]] = &[year_2021::PUZZLES];\n";
+        assert_eq!(
+            add_year_to_registry(registry, 2022),
+            Ok(
+                "use crate::solution::Solution;\nuse crate::year_2021;\nuse crate::year_2022;\n\n\
+                pub static EVENTS: &[&[Puzzle]] = &[year_2021::PUZZLES, year_2022::PUZZLES];\n"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn generate_day_of_new_year() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub mod verify;\n").unwrap();
+        std::fs::write(
+            root.join("src/registry.rs"),
+            "use crate::solution::Solution;\n\npub static EVENTS: &[&[Puzzle]] = &[];\n",
+        )
+        .unwrap();
+
+        let changes = new_day(&root, &root.join("input"), 2022, 1, "Calorie Counting").unwrap();
+        assert_eq!(changes.len(), 9);
+        assert!(changes.contains(&Change::Updated(root.join("src/lib.rs"))));
+        assert!(root
+            .join("src/year_2022/day_01_calorie_counting/part_2.rs")
+            .exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("input/2022/01.txt")).unwrap(),
+            ""
+        );
+        assert!(
+            std::fs::read_to_string(root.join("test_input/2022/examples.txt"))
+                .unwrap()
+                .ends_with("\n01 1 01.txt 0\n01 2 01.txt 0\n")
+        );
+        assert!(std::fs::read_to_string(root.join("src/registry.rs"))
+            .unwrap()
+            .contains("= &[year_2022::PUZZLES];"));
+
+        assert!(new_day(&root, &root.join("input"), 2022, 1, "Calorie Counting").is_err());
+        assert!(new_day(&root, &root.join("input"), 2022, 2, "!!").is_err());
+        assert!(new_day(&root.join("src"), &root.join("input"), 2022, 2, "Dive!").is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest way found from the start to a goal, including both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// Distinct states reached, including the start.
+    pub discovered: usize,
+    /// Largest number of entries waiting in the queue at the same time.
+    pub max_frontier: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S, C> {
+    /// `None` if no goal can be reached from the start.
+    pub path: Option<Path<S, C>>,
+    pub stats: Stats,
+}
+
+/// Finds the cheapest path from `start` to a state satisfying `is_goal`, where `successors` lists
+/// the states reachable from a state with the cost of getting there. Costs should not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, successors, is_goal, |_| C::default())
+}
+
+/// Same as `dijkstra`, exploring first the states for which `heuristic` estimates the lowest total
+/// cost. The path is the cheapest one as long as the heuristic never overestimates the cost left
+/// to reach a goal.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are numbered in the order they are discovered, with the lowest known cost of
+    // reaching each and the state it was reached from.
+    let mut states: Vec<S> = vec![start.clone()];
+    let mut numbers: HashMap<S, usize> = HashMap::from([(start, 0)]);
+    let mut best: Vec<(C, Option<usize>)> = vec![(C::default(), None)];
+    let mut frontier =
+        BinaryHeap::from([(Reverse(heuristic(&states[0])), 0, Reverse(C::default()))]);
+    let mut stats = Stats {
+        discovered: 1,
+        max_frontier: 1,
+        ..Stats::default()
+    };
+
+    while let Some((_, number, Reverse(cost))) = frontier.pop() {
+        if cost > best[number].0 {
+            continue;
+        }
+
+        if is_goal(&states[number]) {
+            return Search {
+                path: Some(reconstruct_path(&states, &best, number)),
+                stats,
+            };
+        }
+
+        stats.expanded += 1;
+
+        for (next_state, step_cost) in successors(&states[number]) {
+            let next_cost = cost + step_cost;
+            let next_number = match numbers.get(&next_state) {
+                Some(&next_number) if best[next_number].0 <= next_cost => continue,
+                Some(&next_number) => {
+                    best[next_number] = (next_cost, Some(number));
+                    next_number
+                }
+                None => {
+                    states.push(next_state.clone());
+                    numbers.insert(next_state, states.len() - 1);
+                    best.push((next_cost, Some(number)));
+                    stats.discovered += 1;
+                    states.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&states[next_number]);
+            frontier.push((Reverse(estimate), next_number, Reverse(next_cost)));
+        }
+
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+    }
+
+    Search { path: None, stats }
+}
+
+fn reconstruct_path<S: Clone, C: Copy>(
+    states: &[S],
+    best: &[(C, Option<usize>)],
+    goal: usize,
+) -> Path<S, C> {
+    let mut numbers = vec![goal];
+
+    while let Some(previous) = best[*numbers.last().unwrap()].1 {
+        numbers.push(previous);
+    }
+
+    Path {
+        states: numbers
+            .into_iter()
+            .rev()
+            .map(|number| states[number].clone())
+            .collect(),
+        cost: best[goal].0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::search::*;
+
+    const MAP: &str = "\
+1163
+1381
+2136
+";
+
+    fn successors(grid: &Grid<u8>, position: (usize, usize)) -> Vec<((usize, usize), u32)> {
+        grid.neighbours_4(position)
+            .map(|neighbour| (neighbour, grid[neighbour] as u32))
+            .collect()
+    }
+
+    #[test]
+    fn find_cheapest_path_and_reconstruct_it() {
+        let grid = Grid::parse_digits(MAP, MAP).unwrap();
+        let end = (3, 2);
+        let search = dijkstra(
+            (0, 0),
+            |&position| successors(&grid, position),
+            |&position| position == end,
+        );
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 13);
+        assert_eq!(
+            path.states,
+            [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)]
+        );
+        assert!(search.stats.expanded < search.stats.discovered);
+        assert!(search.stats.discovered <= 12);
+    }
+
+    #[test]
+    fn expand_fewer_states_with_heuristic() {
+        let grid = Grid::new(20, 20, 1u8);
+        let end = (19, 19);
+        let manhattan = |&(x, y): &(usize, usize)| (end.0 - x + end.1 - y) as u32;
+
+        let dijkstra = dijkstra(
+            (0, 0),
+            |&position| successors(&grid, position),
+            |&position| position == end,
+        );
+        let a_star = a_star(
+            (0, 0),
+            |&position| successors(&grid, position),
+            |&position| position == end,
+            manhattan,
+        );
+        assert_eq!(dijkstra.path.as_ref().unwrap().cost, 38);
+        assert_eq!(a_star.path.as_ref().unwrap().cost, 38);
+        assert_eq!(a_star.path.unwrap().states.len(), 39);
+        assert!(a_star.stats.expanded < dijkstra.stats.expanded);
+    }
+
+    #[test]
+    fn report_unreachable_goal() {
+        let search = dijkstra(1u32, |&n| (n < 10).then_some((n + 1, 1u32)), |&n| n == 0);
+        assert_eq!(search.path, None);
+        assert_eq!(search.stats.discovered, 10);
+        assert_eq!(search.stats.expanded, 10);
+    }
+}
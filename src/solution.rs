@@ -0,0 +1,17 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+
+/// A puzzle of a single day, split into a parse step shared by both parts and the parts themselves.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Number of parts of the puzzle. Day 25 has only one.
+    const PARTS: u8 = 2;
+
+    /// Model of the day's input, parsed once and shared by both parts.
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Answer;
+    fn part_2(input: &Self::Input) -> Answer;
+}
@@ -0,0 +1,439 @@
+use crate::http;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_FILE_NAME: &str = "submissions.txt";
+/// The server asks to wait at least a minute after an answer that was not accepted.
+pub const MINIMUM_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked, because the previous one was submitted too recently. The server
+    /// tells how long is left to wait before the next attempt.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+}
+
+impl Outcome {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::RateLimited { .. } => "rate_limited",
+        }
+    }
+
+    /// Name of the outcome in the history file, followed by the seconds left to wait if the
+    /// server told them, e.g. `rate_limited:36`.
+    fn field(&self) -> String {
+        match self {
+            Outcome::RateLimited { wait: Some(wait) } => {
+                format!("{}:{}", self.name(), wait.as_secs())
+            }
+            _ => self.name().to_owned(),
+        }
+    }
+
+    fn from_field(field: &str) -> Option<Self> {
+        if let Some(seconds) = field.strip_prefix("rate_limited:") {
+            let wait = Duration::from_secs(seconds.parse().ok()?);
+            return Some(Outcome::RateLimited { wait: Some(wait) });
+        }
+
+        match field {
+            "correct" => Some(Outcome::Correct),
+            "wrong" => Some(Outcome::Wrong),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "rate_limited" => Some(Outcome::RateLimited { wait: None }),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the server checked the answer and did not accept it.
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                let (minutes, seconds) = (wait.as_secs() / 60, wait.as_secs() % 60);
+
+                match minutes {
+                    0 => write!(f, "not checked, {}s left to wait", seconds),
+                    _ => write!(f, "not checked, {}m {}s left to wait", minutes, seconds),
+                }
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "not checked, submitted too soon"),
+        }
+    }
+}
+
+/// Reads the outcome from the page returned after submitting an answer.
+pub fn parse_response(page: &str) -> Result<Outcome, String> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        Ok(Outcome::RateLimited { wait })
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err("Not the right level. Is the part already solved or still locked?".to_owned())
+    } else {
+        Err("Unrecognized response to the submitted answer.".to_owned())
+    }
+}
+
+/// Parses a wait such as `4m 51s` or `36s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    text.split_whitespace()
+        .try_fold(Duration::ZERO, |wait, amount| {
+            let unit = amount.chars().last()?;
+            let number: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+            let seconds = match unit {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            Some(wait + Duration::from_secs(number * seconds))
+        })
+}
+
+/// Posts the answer of a part and reads the outcome.
+pub fn submit(
+    settings: &http::Settings,
+    token: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, String> {
+    let form = format!("level={}&answer={}", part, http::encode(answer));
+    let page = http::post_form(&settings.url(year, day, "answer"), token, &form)?;
+    parse_response(&page)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:02} {} {} {}",
+            self.time,
+            self.day,
+            self.part,
+            self.outcome.field(),
+            self.answer
+        )
+    }
+}
+
+/// Submitted answers, one attempt per line: time, day, part, outcome and answer.
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                History::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut history = History::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            history.attempts.push(parse_attempt(line).ok_or_else(|| {
+                format!(
+                    "line {}: expected time, day, part, outcome and answer",
+                    index + 1
+                )
+            })?);
+        }
+
+        Ok(history)
+    }
+
+    /// Appends the attempt to the history file as well.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> Result<(), String> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", attempt))
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Explains why the answer should not be submitted at `now`, e.g. because it was already
+    /// rejected or lies outside the range left by answers that were too high or too low.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.day == day && attempt.part == part)
+        };
+
+        if let Some(correct) = attempts().find(|attempt| attempt.outcome == Outcome::Correct) {
+            return Err(match correct.answer == answer {
+                true => format!("{} was already accepted.", answer),
+                false => format!("The part was already solved with {}.", correct.answer),
+            });
+        }
+
+        if let Some(rejected) =
+            attempts().find(|attempt| attempt.outcome.is_rejection() && attempt.answer == answer)
+        {
+            return Err(format!(
+                "{} was already submitted and it was {}.",
+                answer, rejected.outcome
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            for attempt in attempts() {
+                let Ok(bound) = attempt.answer.parse::<i64>() else {
+                    continue;
+                };
+
+                match attempt.outcome {
+                    Outcome::TooHigh if value >= bound => {
+                        return Err(format!("{} is too high, {} already was.", answer, bound))
+                    }
+                    Outcome::TooLow if value <= bound => {
+                        return Err(format!("{} is too low, {} already was.", answer, bound))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // The wait asked by the server after an answer was not checked replaces the minimum one,
+        // as it grows with repeated attempts.
+        let last_unaccepted = self
+            .attempts
+            .iter()
+            .filter_map(|attempt| match attempt.outcome {
+                Outcome::Correct => None,
+                Outcome::RateLimited { wait: Some(wait) } => Some((attempt.time, wait.as_secs())),
+                _ => Some((attempt.time, MINIMUM_WAIT.as_secs())),
+            })
+            .max_by_key(|(time, wait)| time + wait);
+
+        match last_unaccepted {
+            Some((time, wait)) if now < time + wait => Err(format!(
+                "The last answer was not accepted {}s ago. Wait at least {}s before the next attempt.",
+                now.saturating_sub(time),
+                wait
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let fields: Vec<&str> = line.splitn(5, ' ').collect();
+    let [time, day, part, outcome, answer] = fields[..] else {
+        return None;
+    };
+
+    Some(Attempt {
+        time: time.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        outcome: Outcome::from_field(outcome)?,
+        answer: answer.to_owned(),
+    })
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::stub;
+    use crate::submit::*;
+
+    const HISTORY: &str = "\
+# time day part outcome answer
+1000 01 1 too_high 2000
+1100 01 1 too_low 1000
+1200 01 1 wrong 1500
+1300 02 1 correct 150
+";
+
+    #[test]
+    fn read_outcome_from_response() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Ok(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer. If you're stuck, ...")),
+            Ok(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently. You have 36s left to wait."
+            )),
+            Ok(Outcome::RateLimited {
+                wait: Some(Duration::from_secs(36))
+            })
+        );
+        let outcome = parse_response(&page(
+            "You gave an answer too recently. You have 4m 51s left to wait.",
+        ))
+        .unwrap();
+        assert_eq!(outcome.to_string(), "not checked, 4m 51s left to wait");
+        assert_eq!(parse_wait("1h 2s"), Some(Duration::from_secs(3602)));
+        assert_eq!(parse_wait("soon"), None);
+        assert_eq!(parse_wait("5é"), None);
+        assert!(parse_response(&page("You don't seem to be solving the right level.")).is_err());
+        assert!(parse_response("").is_err());
+    }
+
+    #[test]
+    fn refuse_answers_known_to_be_wrong() {
+        let history = History::parse(HISTORY).unwrap();
+        assert!(history.check(1, 1, "1500", 2000).is_err());
+        assert!(history.check(1, 1, "2500", 2000).is_err());
+        assert!(history.check(1, 1, "999", 2000).is_err());
+        assert_eq!(history.check(1, 1, "1602", 2000), Ok(()));
+        assert_eq!(
+            history.check(2, 1, "150", 2000),
+            Err("150 was already accepted.".to_owned())
+        );
+        assert!(history.check(2, 1, "151", 2000).is_err());
+        assert_eq!(history.check(1, 2, "1500", 2000), Ok(()));
+        assert!(history.check(1, 2, "1500", 1230).is_err());
+
+        let history = History::parse("1000 01 1 rate_limited:300 1500\n").unwrap();
+        assert_eq!(
+            history.check(1, 1, "1500", 1100),
+            Err(
+                "The last answer was not accepted 100s ago. Wait at least 300s before the next attempt."
+                    .to_owned()
+            )
+        );
+        assert_eq!(history.check(1, 1, "1500", 1300), Ok(()));
+        assert_eq!(
+            history.attempts[0].to_string(),
+            "1000 01 1 rate_limited:300 1500"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_history() {
+        assert!(History::parse("1000 01 1 maybe 7").is_err());
+        assert!(History::parse("1000 01 1 wrong").is_err());
+        assert_eq!(
+            History::parse("1000 01 1 wrong 7\nsoon 01 1 wrong 8"),
+            Err("line 2: expected time, day, part, outcome and answer".to_owned())
+        );
+    }
+
+    #[test]
+    fn submit_answer_and_record_attempt() {
+        let (base_url, server) = stub::serve(1, |request| match request.body.as_str() {
+            "level=2&answer=1633" => ("200 OK", "<p>That's the right answer!</p>".to_owned()),
+            _ => ("200 OK", "<p>That's not the right answer.</p>".to_owned()),
+        });
+        let settings = http::Settings {
+            base_url,
+            ..http::Settings::default()
+        };
+
+        let outcome = submit(&settings, "abc", 2021, 1, 2, "1633");
+        assert_eq!(outcome, Ok(Outcome::Correct));
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2021/day/1/answer");
+
+        let path = std::env::temp_dir().join(format!("aoc_history_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut history = History::read(&path).unwrap();
+        let attempt = Attempt {
+            time: 1000,
+            day: 1,
+            part: 2,
+            outcome: Outcome::Correct,
+            answer: "1633".to_owned(),
+        };
+        history.record(&path, attempt.clone()).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "1000 01 2 correct 1633\n"
+        );
+        assert_eq!(History::read(&path).unwrap(), history);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
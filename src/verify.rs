@@ -0,0 +1,284 @@
+use crate::answer::Answer;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Missing => "missing",
+        }
+    }
+}
+
+/// Expected answers keyed by day and part, read from a subset of TOML:
+///
+/// ```toml
+/// [day_01]
+/// part_1 = 1602
+/// part_2 = "1633"
+///
+/// [day_13]
+/// part_2 = """
+/// ███  █
+/// █  █ █"""
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    expected: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        Answers::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+        let mut day = None;
+        let mut lines = content.lines().enumerate();
+
+        while let Some((index, line)) = lines.next() {
+            let line_number = index + 1;
+            let error = |message: &str| format!("line {}: {}", line_number, message);
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let name = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("unterminated table header"))?;
+                day = Some(
+                    parse_key(name.trim(), "day_", 25)
+                        .ok_or_else(|| error("expected a table named day_01 to day_25"))?,
+                );
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected key = value"))?;
+            let day = day.ok_or_else(|| error("answer outside of a [day_NN] table"))?;
+            let part = parse_key(key.trim(), "part_", 2)
+                .ok_or_else(|| error("expected part_1 or part_2"))?;
+            let value = value.trim();
+
+            let expected = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                let mut text = String::new();
+                let mut rest = rest.to_owned();
+
+                loop {
+                    if let Some(end) = rest.find("\"\"\"") {
+                        text.push_str(&rest[..end]);
+                        break;
+                    }
+
+                    text.push_str(&rest);
+                    text.push('\n');
+                    rest = match lines.next() {
+                        Some((_, next_line)) => next_line.to_owned(),
+                        None => return Err(error("unterminated multi-line string")),
+                    };
+                }
+
+                text.strip_prefix('\n').map(str::to_owned).unwrap_or(text)
+            } else {
+                parse_value(value).map_err(error)?
+            };
+
+            if answers.expected.insert((day, part), expected).is_some() {
+                return Err(error("duplicate answer"));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Trailing whitespace of each line is ignored, so the rows of a grid answer can be stored
+    /// without the spaces editors tend to strip anyway.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        let Some(expected) = self.get(day, part) else {
+            return Verdict::Missing;
+        };
+        if matches(expected, answer) {
+            Verdict::Pass
+        } else {
+            Verdict::Fail {
+                expected: expected.to_owned(),
+            }
+        }
+    }
+}
+
+/// Compares an answer with the expected one, ignoring trailing whitespace of each line.
+pub fn matches(expected: &str, answer: &Answer) -> bool {
+    expected.trim_end().lines().map(str::trim_end).eq(answer
+        .to_string()
+        .trim_end()
+        .lines()
+        .map(str::trim_end))
+}
+
+/// Parses a single-line value: an integer, which may contain `_` separators, or a basic or literal
+/// string. Either may be followed by a comment.
+pub(crate) fn parse_value(value: &str) -> Result<String, &'static str> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let end = closing_quote(rest).ok_or("unterminated string")?;
+        only_comment(&rest[end + 1..])?;
+        unescape(&rest[..end]).ok_or("invalid escape sequence")
+    } else if let Some(rest) = value.strip_prefix('\'') {
+        let end = rest.find('\'').ok_or("unterminated string")?;
+        only_comment(&rest[end + 1..])?;
+        Ok(rest[..end].to_owned())
+    } else {
+        let number = value.split('#').next().unwrap().trim().replace('_', "");
+        number
+            .parse::<i64>()
+            .map(|number| number.to_string())
+            .map_err(|_| "expected an integer or a string")
+    }
+}
+
+/// Index of the `"` ending a basic string, skipping escaped ones.
+fn closing_quote(text: &str) -> Option<usize> {
+    let mut escaped = false;
+
+    text.char_indices()
+        .find(|&(_, c)| {
+            let closing = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            closing
+        })
+        .map(|(index, _)| index)
+}
+
+fn only_comment(text: &str) -> Result<(), &'static str> {
+    match text.trim_start() {
+        rest if rest.is_empty() || rest.starts_with('#') => Ok(()),
+        _ => Err("unexpected text after string"),
+    }
+}
+
+fn parse_key(key: &str, prefix: &str, max: u8) -> Option<u8> {
+    match key.strip_prefix(prefix)?.parse::<u8>() {
+        Ok(number) if (1..=max).contains(&number) => Some(number),
+        _ => None,
+    }
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            '"' => output.push('"'),
+            '\\' => output.push('\\'),
+            'n' => output.push('\n'),
+            't' => output.push('\t'),
+            _ => return None,
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify::*;
+
+    const ANSWERS: &str = r#"
+# Answers for the example input.
+[day_01]
+part_1 = 7
+part_2 = "5" # Quoted, with a comment.
+
+[day_13]
+part_1 = 17 # Counted after the first fold.
+part_2 = """
+█████
+█   █
+█████"""
+
+[day_24]
+part_1 = '92_969_593_497_992'
+part_2 = "say \"hi\" # twice" # A comment after the string.
+"#;
+
+    #[test]
+    fn parse_answers_file() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 6);
+        assert_eq!(answers.get(1, 1), Some("7"));
+        assert_eq!(answers.get(1, 2), Some("5"));
+        assert_eq!(answers.get(13, 1), Some("17"));
+        assert_eq!(answers.get(13, 2), Some("█████\n█   █\n█████"));
+        assert_eq!(answers.get(24, 1), Some("92_969_593_497_992"));
+        assert_eq!(answers.get(24, 2), Some("say \"hi\" # twice"));
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, &Answer::Integer(7)), Verdict::Pass);
+        assert_eq!(answers.check(1, 2, &Answer::Integer(5)), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 2, &Answer::Integer(6)),
+            Verdict::Fail {
+                expected: "5".to_owned()
+            }
+        );
+        let grid = ["█████", "█   █", "█████"].map(str::to_owned).to_vec();
+        assert_eq!(answers.check(13, 2, &Answer::Grid(grid)), Verdict::Pass);
+        assert_eq!(answers.check(2, 1, &Answer::Integer(150)), Verdict::Missing);
+    }
+
+    #[test]
+    fn reject_invalid_answers_file() {
+        assert!(Answers::parse("part_1 = 7").is_err());
+        assert!(Answers::parse("[day_26]").is_err());
+        assert!(Answers::parse("[day_01]\npart_3 = 7").is_err());
+        assert!(Answers::parse("[day_01]\npart_1 = seven").is_err());
+        assert!(Answers::parse("[day_01]\npart_1 = \"7").is_err());
+        assert!(Answers::parse("[day_01]\npart_1 = \"7\" 8").is_err());
+        assert!(Answers::parse("[day_01]\npart_1 = 7\npart_1 = 8").is_err());
+        assert!(Answers::parse("[day_13]\npart_2 = \"\"\"\n█").is_err());
+        assert_eq!(
+            Answers::parse("[day_01]\n\npart_1 = x"),
+            Err("line 3: expected an integer or a string".to_owned())
+        );
+    }
+}
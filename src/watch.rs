@@ -0,0 +1,255 @@
+use crate::answer::Answer;
+use crate::examples::{self, MANIFEST_FILE_NAME};
+use crate::input::{self, InputSource, EXAMPLES_DIRECTORY};
+use crate::registry::Puzzle;
+use crate::runner;
+use crate::verify;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How often watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Time allowed for parsing and for each part when `--timeout` is not given, so that a solver
+/// stuck on a half-edited input does not stop the watch.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Contents of the watched files, hashed, so that a change is noticed however quickly it follows
+/// the previous one. A missing file is `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot(Vec<(PathBuf, Option<u64>)>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Snapshot(
+            paths
+                .iter()
+                .map(|path| {
+                    let hash = std::fs::read(path).ok().map(|content| {
+                        let mut hasher = DefaultHasher::new();
+                        content.hash(&mut hasher);
+                        hasher.finish()
+                    });
+                    (path.clone(), hash)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Answers of the parts of a day, or why each could not be solved. A solver that panics or runs
+/// out of time is reported like a malformed input.
+fn solve_with(
+    source: &InputSource,
+    puzzles: &[&Puzzle],
+    timeout: Duration,
+) -> Vec<Result<Answer, String>> {
+    let outcome = runner::solve(source, puzzles, 1, Some(timeout)).remove(0);
+    let mut answers: Vec<Result<Answer, String>> = outcome
+        .solved
+        .into_iter()
+        .map(|solved| Ok(solved.answer))
+        .collect();
+
+    if let Some(error) = outcome.error {
+        answers.resize(puzzles.len(), Err(error.to_string()));
+    }
+
+    answers
+}
+
+/// Files that affect the answers of the day: its input, its examples and the manifest listing them.
+pub fn watched_files(source: &InputSource, year: u16, day: u8) -> Vec<PathBuf> {
+    let examples_directory = input::year_directory(Path::new(EXAMPLES_DIRECTORY), year);
+    let mut paths: Vec<PathBuf> = source.path(year, day).into_iter().collect();
+    paths.push(examples_directory.join(MANIFEST_FILE_NAME));
+
+    if let Ok(manifest) = examples::read_manifest(year) {
+        for example in manifest.into_iter().filter(|example| example.day == day) {
+            let path = examples_directory.join(&example.file_name);
+
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Answers of one run of a day, in the order they were solved, keyed by part and input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Run {
+    pub answers: Vec<(String, String)>,
+}
+
+impl Run {
+    /// Solves the examples of the selected parts of a day, followed by its input. Examples are
+    /// marked with whether they match the answers in the manifest.
+    pub fn solve(source: &InputSource, puzzles: &[&Puzzle], timeout: Duration) -> Self {
+        let mut run = Run::default();
+        let Some(first) = puzzles.first() else {
+            return run;
+        };
+
+        match examples::read_manifest(first.year) {
+            Ok(manifest) => {
+                for puzzle in puzzles {
+                    let examples = manifest
+                        .iter()
+                        .filter(|example| (example.day, example.part) == (puzzle.day, puzzle.part));
+
+                    for example in examples {
+                        let key = format!("Part {} with {}", puzzle.part, example.file_name);
+                        let path = input::year_directory(Path::new(EXAMPLES_DIRECTORY), first.year)
+                            .join(&example.file_name);
+                        let answer =
+                            solve_with(&InputSource::File(path), &[puzzle], timeout).remove(0);
+                        let value = match answer {
+                            Ok(answer) if verify::matches(&example.expected, &answer) => {
+                                format!("{answer} [pass]")
+                            }
+                            Ok(answer) => format!("{answer} [fail, expected {}]", example.expected),
+                            Err(error) => error,
+                        };
+                        run.answers.push((key, value));
+                    }
+                }
+            }
+            Err(error) => run.answers.push((
+                MANIFEST_FILE_NAME.to_owned(),
+                format!("unreadable: {error}"),
+            )),
+        }
+
+        let input_name = source
+            .path(first.year, first.day)
+            .map_or_else(|| source.to_string(), |path| path.display().to_string());
+
+        for (puzzle, answer) in puzzles.iter().zip(solve_with(source, puzzles, timeout)) {
+            let value = answer.map_or_else(|error| error, |answer| answer.to_string());
+            run.answers
+                .push((format!("Part {} with {}", puzzle.part, input_name), value));
+        }
+
+        run
+    }
+
+    /// Describes each answer compared with the previous run: changed ones show both values.
+    pub fn diff(&self, previous: Option<&Run>) -> Vec<String> {
+        self.answers
+            .iter()
+            .map(|(key, value)| {
+                let previous_value = previous.and_then(|previous| {
+                    previous
+                        .answers
+                        .iter()
+                        .find(|(previous_key, _)| previous_key == key)
+                        .map(|(_, value)| value)
+                });
+
+                match previous_value {
+                    None => format!("{key}: {}", on_own_lines(value)),
+                    Some(previous_value) if previous_value == value => {
+                        format!("{key}: {} (unchanged)", on_own_lines(value))
+                    }
+                    Some(previous_value) => format!(
+                        "{key}: {} -> {}",
+                        on_own_lines(previous_value),
+                        on_own_lines(value)
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Grids are printed below the key, so their rows stay aligned.
+fn on_own_lines(value: &str) -> String {
+    match value.contains('\n') {
+        true => format!("\n{value}\n"),
+        false => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use crate::watch::*;
+
+    #[test]
+    fn notice_changed_and_removed_files() {
+        let path = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let paths = [path.clone()];
+        std::fs::write(&path, "199\n").unwrap();
+        let snapshot = Snapshot::take(&paths);
+        assert_eq!(Snapshot::take(&paths), snapshot);
+
+        std::fs::write(&path, "200\n").unwrap();
+        let changed = Snapshot::take(&paths);
+        assert_ne!(changed, snapshot);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_ne!(Snapshot::take(&paths), changed);
+    }
+
+    #[test]
+    fn watch_input_and_examples_of_the_day() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let paths = watched_files(&source, 2021, 12);
+        let names: Vec<String> = paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["12.txt", "examples.txt", "12_2.txt", "12_3.txt"]);
+    }
+
+    #[test]
+    fn compare_answers_with_previous_run() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let puzzles: Vec<&Puzzle> = registry::puzzles()
+            .filter(|puzzle| (puzzle.year, puzzle.day) == (2021, 1))
+            .collect();
+        let run = Run::solve(&source, &puzzles, DEFAULT_TIMEOUT);
+        let path = source.path(2021, 1).unwrap();
+        let path = path.display();
+        assert_eq!(
+            run.diff(None),
+            [
+                "Part 1 with 01.txt: 7 [pass]".to_owned(),
+                "Part 2 with 01.txt: 5 [pass]".to_owned(),
+                format!("Part 1 with {path}: 7"),
+                format!("Part 2 with {path}: 5"),
+            ]
+        );
+
+        let mut previous = run.clone();
+        previous.answers[2].1 = "8".to_owned();
+        previous.answers.pop();
+        assert_eq!(
+            run.diff(Some(&previous))[1..],
+            [
+                "Part 2 with 01.txt: 5 [pass] (unchanged)".to_owned(),
+                format!("Part 1 with {path}: 8 -> 7"),
+                format!("Part 2 with {path}: 5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_panic_without_stopping() {
+        let source: InputSource = "./test_input".parse().unwrap();
+        let first = registry::find(2021, 1, 1).unwrap();
+        let panicking = Puzzle {
+            run: |_| panic!("index out of bounds"),
+            ..*first
+        };
+        let run = Run::solve(&source, &[first, &panicking], DEFAULT_TIMEOUT);
+        assert_eq!(
+            run.answers.last().unwrap().1,
+            "solver panicked: index out of bounds"
+        );
+        assert_eq!(run.answers[2].1, "7");
+    }
+}
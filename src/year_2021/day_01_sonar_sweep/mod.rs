@@ -0,0 +1,34 @@
+use crate::answer::Answer;
+use crate::parse::{parse_as, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_as(input, line.trim_end(), "a depth measurement"))
+        .collect()
+}
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(report: &Self::Input) -> Answer {
+        part_1::count_number_of_depth_measurement_increases(report).into()
+    }
+
+    fn part_2(report: &Self::Input) -> Answer {
+        part_2::count_number_of_measurement_sums_increases(report).into()
+    }
+}
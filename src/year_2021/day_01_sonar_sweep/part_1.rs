@@ -0,0 +1,22 @@
+pub fn count_number_of_depth_measurement_increases(sweep_report: &[u32]) -> usize {
+    sweep_report
+        .iter()
+        .zip(sweep_report.iter().skip(1))
+        .filter(|(&x, &y)| x < y)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_01_sonar_sweep::parse;
+    use crate::year_2021::day_01_sonar_sweep::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let report = parse(&read_example("01.txt").unwrap()).unwrap();
+        assert_eq!(report.len(), 10);
+        let result = count_number_of_depth_measurement_increases(&report);
+        assert_eq!(result, 7);
+    }
+}
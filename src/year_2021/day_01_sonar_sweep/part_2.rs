@@ -0,0 +1,24 @@
+pub fn count_number_of_measurement_sums_increases(sweep_report: &[u32]) -> usize {
+    sweep_report
+        .windows(3)
+        .map(|window| window.iter().sum::<u32>())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .filter(|&pair| pair[0] < pair[1])
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_01_sonar_sweep::parse;
+    use crate::year_2021::day_01_sonar_sweep::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let report = parse(&read_example("01.txt").unwrap()).unwrap();
+        assert_eq!(report.len(), 10);
+        let result = count_number_of_measurement_sums_increases(&report);
+        assert_eq!(result, 5);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::answer::Answer;
+use crate::parse::{parse_as, split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+#[derive(Clone, Copy)]
+pub enum Command {
+    Forward(u8),
+    Up(u8),
+    Down(u8),
+}
+
+impl std::str::FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, value) = split_once(s, s.trim_end(), " ")?;
+        let value = parse_as::<u8>(s, value, "a number")?;
+
+        match command {
+            "forward" => Ok(Command::Forward(value)),
+            "up" => Ok(Command::Up(value)),
+            "down" => Ok(Command::Down(value)),
+            _ => Err(ParseError::at(
+                s,
+                command,
+                format!("expected forward, down or up, found {command:?}"),
+            )),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|error: ParseError| error.within(input, line))
+        })
+        .collect()
+}
+
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(commands: &Self::Input) -> Answer {
+        part_1::find_submarine_position(commands).into()
+    }
+
+    fn part_2(commands: &Self::Input) -> Answer {
+        part_2::find_submarine_position(commands).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_02_dive::*;
+
+    #[test]
+    fn report_malformed_command() {
+        let error = parse("forward 5\nsideways 3\n").err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(2, 1, "expected forward, down or up, found \"sideways\"")
+        );
+        let error = parse("forward 5\ndown x\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::year_2021::day_02_dive::Command;
+
+pub fn find_submarine_position(planned_course: &[Command]) -> u32 {
+    let mut x_pos: u32 = 0;
+    let mut depth: u32 = 0;
+    let mut aim: u32 = 0;
+
+    for &command in planned_course {
+        match command {
+            Command::Forward(value) => {
+                x_pos += value as u32;
+                depth += aim * value as u32;
+            }
+            Command::Up(value) => aim -= value as u32,
+            Command::Down(value) => aim += value as u32,
+        }
+    }
+
+    x_pos * depth
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_02_dive::parse;
+    use crate::year_2021::day_02_dive::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let commands = parse(&read_example("02.txt").unwrap()).unwrap();
+        assert_eq!(commands.len(), 6);
+        let result = find_submarine_position(&commands);
+        assert_eq!(result, 900);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::answer::Answer;
+use crate::parse::{grid_lines, only_chars, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Widest line whose rates fit in a `u32` and multiply to an answer that fits in an `i64`.
+const MAX_WIDTH: usize = 31;
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines = grid_lines(input, input)?;
+    if lines[0].len() > MAX_WIDTH {
+        return Err(ParseError::at(
+            input,
+            lines[0],
+            format!(
+                "expected at most {MAX_WIDTH} bits, found {}",
+                lines[0].len()
+            ),
+        ));
+    }
+
+    lines
+        .into_iter()
+        .map(|line| only_chars(input, line, "01", "a bit").map(str::to_owned))
+        .collect()
+}
+
+fn rate(bits: &str) -> u64 {
+    u64::from(u32::from_str_radix(bits, 2).expect("The line width should be checked by parse"))
+}
+
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(diagnostic_report: &Self::Input) -> Answer {
+        let (gamma, epsilon) = part_1::calculate_gamma_and_epsilon_rates(diagnostic_report);
+        (rate(&gamma) * rate(&epsilon)).into()
+    }
+
+    fn part_2(diagnostic_report: &Self::Input) -> Answer {
+        let oxygen = part_2::filter_report(diagnostic_report, part_2::Mode::MostCommon);
+        let co2 = part_2::filter_report(diagnostic_report, part_2::Mode::LeastCommon);
+        (rate(&oxygen) * rate(&co2)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_03_binary_diagnostic::*;
+
+    #[test]
+    fn reject_lines_wider_than_an_answer() {
+        let input = format!("{}\n{}\n", "1".repeat(32), "0".repeat(32));
+        assert_eq!(
+            parse(&input).err(),
+            Some(ParseError::new(1, 1, "expected at most 31 bits, found 32"))
+        );
+
+        let input = format!("{}\n{}\n", "1".repeat(31), "0".repeat(31));
+        let report = parse(&input).unwrap();
+        assert_eq!(BinaryDiagnostic::part_1(&report), Answer::Integer(0));
+    }
+}
@@ -0,0 +1,39 @@
+pub fn calculate_gamma_and_epsilon_rates(report: &[String]) -> (String, String) {
+    let report_len = report.len();
+    let mut counts: Vec<usize> = vec![0; report[0].len()];
+
+    for binary_number in report {
+        for (index, char) in binary_number.chars().enumerate() {
+            if char == '1' {
+                counts[index] += 1;
+            }
+        }
+    }
+
+    let gamma_rate: String = counts
+        .iter()
+        .map(|&count| if count > (report_len / 2) { '1' } else { '0' })
+        .collect();
+
+    let epsilon_rate: String = gamma_rate
+        .chars()
+        .map(|char| if char == '0' { '1' } else { '0' })
+        .collect();
+
+    (gamma_rate, epsilon_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_03_binary_diagnostic::parse;
+    use crate::year_2021::day_03_binary_diagnostic::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let diagnostic_report = parse(&read_example("03.txt").unwrap()).unwrap();
+        assert_eq!(diagnostic_report.len(), 12);
+        let result = calculate_gamma_and_epsilon_rates(&diagnostic_report);
+        assert_eq!(result, ("10110".to_owned(), "01001".to_owned()));
+    }
+}
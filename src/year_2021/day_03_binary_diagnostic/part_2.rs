@@ -1,19 +1,8 @@
-use std::io::{self, BufRead};
-
-enum Mode {
+pub enum Mode {
     LeastCommon,
     MostCommon,
 }
 
-fn process_data(path: &str) -> Vec<String> {
-    let file = std::fs::File::open(path).unwrap();
-
-    io::BufReader::new(file)
-        .lines()
-        .map(|line| line.unwrap())
-        .collect::<Vec<String>>()
-}
-
 fn compare_number_of_ones_against_zeroes(report: &[String], index: usize) -> std::cmp::Ordering {
     let count_of_ones: usize = report
         .iter()
@@ -23,7 +12,8 @@ fn compare_number_of_ones_against_zeroes(report: &[String], index: usize) -> std
     count_of_ones.cmp(&(report.len() - count_of_ones))
 }
 
-fn filter_report(mut report: Vec<String>, mode: Mode) -> String {
+pub fn filter_report(report: &[String], mode: Mode) -> String {
+    let mut report = report.to_vec();
     let mut index: usize = 0;
 
     while report.len() > 1 {
@@ -50,26 +40,18 @@ fn filter_report(mut report: Vec<String>, mode: Mode) -> String {
     report.remove(0)
 }
 
-pub fn solve() -> String {
-    let diagnostic_report = process_data("./input/03.txt");
-    let oxygen = filter_report(diagnostic_report.clone(), Mode::MostCommon);
-    let co2 = filter_report(diagnostic_report, Mode::LeastCommon);
-    format!(
-        "Day 3: Binary Diagnostic (Part 2) answer: {:?}.",
-        u32::from_str_radix(&oxygen, 2).unwrap() * u32::from_str_radix(&co2, 2).unwrap()
-    )
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_03_binary_diagnostic::part_2::*;
+    use crate::year_2021::day_03_binary_diagnostic::parse;
+    use crate::year_2021::day_03_binary_diagnostic::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let diagnostic_report = process_data("./test_input/03.txt");
+        let diagnostic_report = parse(&read_example("03.txt").unwrap()).unwrap();
         assert_eq!(diagnostic_report.len(), 12);
-        let oxygen = filter_report(diagnostic_report.clone(), Mode::MostCommon);
-        let co2 = filter_report(diagnostic_report, Mode::LeastCommon);
+        let oxygen = filter_report(&diagnostic_report, Mode::MostCommon);
+        let co2 = filter_report(&diagnostic_report, Mode::LeastCommon);
         assert_eq!(u32::from_str_radix(&oxygen, 2).unwrap(), 23);
         assert_eq!(u32::from_str_radix(&co2, 2).unwrap(), 10);
     }
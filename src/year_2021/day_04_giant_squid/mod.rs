@@ -0,0 +1,57 @@
+use crate::answer::Answer;
+use crate::parse::{integers, parse_as, sections, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub struct Bingo {
+    pub drawn_numbers: Vec<u8>,
+    /// Numbers of each board, row by row.
+    pub boards: Vec<[u8; 25]>,
+}
+
+pub fn parse(input: &str) -> Result<Bingo, ParseError> {
+    let sections = sections(input);
+    let (drawn_numbers, boards) = sections
+        .split_first()
+        .ok_or_else(|| ParseError::end_of_input(input, "expected drawn numbers"))?;
+    let drawn_numbers = integers(input, drawn_numbers, "a drawn number")?;
+    let boards = boards
+        .iter()
+        .map(|&board| {
+            board
+                .split_whitespace()
+                .map(|number| parse_as::<u8>(input, number, "a board number"))
+                .collect::<Result<Vec<u8>, _>>()?
+                .try_into()
+                .map_err(|_| ParseError::at(input, board, "expected 5 rows of 5 numbers"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Bingo {
+        drawn_numbers,
+        boards,
+    })
+}
+
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Input = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(bingo: &Self::Input) -> Answer {
+        part_1::play_bingo(bingo).into()
+    }
+
+    fn part_2(bingo: &Self::Input) -> Answer {
+        part_2::play_bingo(bingo).into()
+    }
+}
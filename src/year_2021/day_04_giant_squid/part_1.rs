@@ -0,0 +1,75 @@
+use crate::year_2021::day_04_giant_squid::Bingo;
+use std::collections::HashMap;
+
+pub struct Board {
+    numbers: HashMap<u8, (u8, u8)>,
+    marked_per_row_count: [u8; 5],
+    marked_per_column_count: [u8; 5],
+}
+
+impl Board {
+    fn from(numbers: &[u8]) -> Self {
+        let mut numbers_map: HashMap<u8, (u8, u8)> = HashMap::new();
+
+        for (index, number) in numbers.iter().enumerate() {
+            numbers_map.insert(*number, (index as u8 / 5u8, index as u8 % 5u8));
+        }
+
+        Board {
+            numbers: numbers_map,
+            marked_per_column_count: [0; 5],
+            marked_per_row_count: [0; 5],
+        }
+    }
+
+    fn bingo(&mut self, number: u8) -> bool {
+        if let Some((column, row)) = self.numbers.remove(&number) {
+            self.marked_per_column_count[column as usize] += 1;
+            self.marked_per_row_count[row as usize] += 1;
+
+            if self.marked_per_column_count[column as usize] == 5
+                || self.marked_per_row_count[row as usize] == 5
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn calculate_unmarked_numbers_sum(&self) -> u32 {
+        self.numbers.keys().map(|key| *key as u32).sum::<u32>()
+    }
+}
+
+pub fn play_bingo(bingo: &Bingo) -> u32 {
+    let mut bingo_boards: Vec<Board> = bingo
+        .boards
+        .iter()
+        .map(|numbers| Board::from(numbers))
+        .collect();
+    for &drawn_number in &bingo.drawn_numbers {
+        for bingo_board in &mut bingo_boards {
+            if bingo_board.bingo(drawn_number) {
+                return bingo_board.calculate_unmarked_numbers_sum() * drawn_number as u32;
+            }
+        }
+    }
+
+    panic!("No bingo board contained bingo");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_04_giant_squid::parse;
+    use crate::year_2021::day_04_giant_squid::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let bingo = parse(&read_example("04.txt").unwrap()).unwrap();
+        assert_eq!(bingo.drawn_numbers.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
+        let result = play_bingo(&bingo);
+        assert_eq!(result, 4512);
+    }
+}
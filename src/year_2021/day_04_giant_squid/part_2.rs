@@ -1,6 +1,7 @@
+use crate::year_2021::day_04_giant_squid::Bingo;
 use std::collections::HashMap;
 
-struct Board {
+pub struct Board {
     numbers: HashMap<u8, (u8, u8)>,
     marked_per_row_count: [u8; 5],
     marked_per_column_count: [u8; 5],
@@ -8,10 +9,10 @@ struct Board {
 }
 
 impl Board {
-    fn from(numbers: &[Vec<u8>]) -> Board {
+    fn from(numbers: &[u8]) -> Board {
         let mut numbers_map: HashMap<u8, (u8, u8)> = HashMap::new();
 
-        for (index, number) in numbers.iter().flatten().enumerate() {
+        for (index, number) in numbers.iter().enumerate() {
             numbers_map.insert(*number, (index as u8 / 5u8, index as u8 % 5u8));
         }
 
@@ -42,35 +43,15 @@ impl Board {
     }
 }
 
-fn process_data(path: &str) -> (Vec<u8>, Vec<Board>) {
-    let file_content = std::fs::read_to_string(path).unwrap();
-    let mut lines = file_content.lines();
-
-    let drawn_numbers = lines
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|number| number.parse::<u8>().unwrap())
+pub fn play_bingo(bingo: &Bingo) -> u32 {
+    let mut bingo_boards: Vec<Board> = bingo
+        .boards
+        .iter()
+        .map(|numbers| Board::from(numbers))
         .collect();
-    let bingo_boards = lines
-        .filter(|&line| !line.is_empty())
-        .map(|line| {
-            line.split_whitespace()
-                .map(|number| number.parse::<u8>().unwrap())
-                .collect::<Vec<u8>>()
-        })
-        .collect::<Vec<Vec<u8>>>()
-        .chunks(5)
-        .map(Board::from)
-        .collect::<Vec<Board>>();
-
-    (drawn_numbers, bingo_boards)
-}
-
-fn play_bingo(drawn_numbers: Vec<u8>, mut bingo_boards: Vec<Board>) -> u32 {
     let mut win_record: Vec<u32> = Vec::new();
 
-    for drawn_number in drawn_numbers {
+    for &drawn_number in &bingo.drawn_numbers {
         for bingo_board in &mut bingo_boards {
             if bingo_board.bingo(drawn_number) {
                 let score = bingo_board.calculate_unmarked_numbers_sum() * drawn_number as u32;
@@ -83,22 +64,18 @@ fn play_bingo(drawn_numbers: Vec<u8>, mut bingo_boards: Vec<Board>) -> u32 {
     *win_record.last().unwrap()
 }
 
-pub fn solve() -> String {
-    let (drawn_numbers, bingo_boards) = process_data("./input/04.txt");
-    let result = play_bingo(drawn_numbers, bingo_boards);
-    format!("Day 4: Giant Squid (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_04_giant_squid::part_2::*;
+    use crate::year_2021::day_04_giant_squid::parse;
+    use crate::year_2021::day_04_giant_squid::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let (drawn_numbers, bingo_boards) = process_data("./test_input/04.txt");
-        assert_eq!(drawn_numbers.len(), 27);
-        assert_eq!(bingo_boards.len(), 3);
-        let result = play_bingo(drawn_numbers, bingo_boards);
+        let bingo = parse(&read_example("04.txt").unwrap()).unwrap();
+        assert_eq!(bingo.drawn_numbers.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
+        let result = play_bingo(&bingo);
         assert_eq!(result, 1924);
     }
 }
@@ -0,0 +1,48 @@
+use crate::answer::Answer;
+use crate::parse::{segment, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+#[derive(Eq, Hash, PartialEq)]
+pub struct Point(u16, u16);
+
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let [(x1, y1), (x2, y2)] = segment(input, line, "a coordinate")?;
+            Ok(Line {
+                start: Point(x1, y1),
+                end: Point(x2, y2),
+            })
+        })
+        .collect()
+}
+
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(hydrothermal_vents: &Self::Input) -> Answer {
+        part_1::count_vent_overlaps(hydrothermal_vents).into()
+    }
+
+    fn part_2(hydrothermal_vents: &Self::Input) -> Answer {
+        part_2::count_vent_overlaps(hydrothermal_vents).into()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::year_2021::day_05_hydrothermal_venture::{Line, Point};
+
+fn calculate_number_of_columns_and_rows(vents: &[Line]) -> (usize, usize) {
+    let (column, row) = vents.iter().fold((0, 0), |(max_x, max_y), vent| {
+        let Point(x1, y1) = vent.start;
+        let Point(x2, y2) = vent.end;
+        (max_x.max(x1).max(x2), max_y.max(y1).max(y2))
+    });
+    (column as usize + 1, row as usize + 1)
+}
+
+fn calculate_step(value: u16, other_value: u16) -> i32 {
+    match value.cmp(&other_value) {
+        std::cmp::Ordering::Less => 1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => -1,
+    }
+}
+
+pub fn count_vent_overlaps(vents: &[Line]) -> usize {
+    let (number_of_columns, number_of_rows) = calculate_number_of_columns_and_rows(vents);
+    let mut vent_map = vec![0u16; number_of_rows * number_of_columns];
+
+    for vent in vents {
+        let Point(x1, y1) = vent.start;
+        let Point(x2, y2) = vent.end;
+        let dx = calculate_step(x1, x2);
+        let dy = calculate_step(y1, y2);
+        let mut x = x1 as i32;
+        let mut y = y1 as i32;
+
+        while x != x2 as i32 || y != y2 as i32 {
+            vent_map[x as usize + y as usize * number_of_columns] += 1;
+            x += dx;
+            y += dy;
+        }
+        vent_map[x as usize + y as usize * number_of_columns] += 1;
+    }
+
+    vent_map.iter().filter(|&&value| value > 1).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_05_hydrothermal_venture::parse;
+    use crate::year_2021::day_05_hydrothermal_venture::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let hydrothermal_vents = parse(&read_example("05.txt").unwrap()).unwrap();
+        assert_eq!(hydrothermal_vents.len(), 10);
+        let result = count_vent_overlaps(&hydrothermal_vents);
+        assert_eq!(result, 12);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::answer::Answer;
+use crate::parse::{integers, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Number of lanternfish with each internal timer value, from 0 to 8.
+pub fn parse(input: &str) -> Result<[usize; 9], ParseError> {
+    let mut grouped_fish = [0usize; 9];
+
+    let timers = integers::<usize>(input, input, "a timer value from 0 to 8")?;
+
+    for (timer, number) in timers.into_iter().zip(input.split(',')) {
+        match grouped_fish.get_mut(timer) {
+            Some(count) => *count += 1,
+            None => {
+                return Err(ParseError::at(
+                    input,
+                    number,
+                    "expected a timer value from 0 to 8",
+                ))
+            }
+        }
+    }
+
+    Ok(grouped_fish)
+}
+
+pub struct Lanternfish;
+
+impl Solution for Lanternfish {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input = [usize; 9];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(grouped_lanternfish: &Self::Input) -> Answer {
+        part_1::play_fish_game(*grouped_lanternfish, 80).into()
+    }
+
+    fn part_2(grouped_lanternfish: &Self::Input) -> Answer {
+        part_2::play_fish_game(*grouped_lanternfish, 256).into()
+    }
+}
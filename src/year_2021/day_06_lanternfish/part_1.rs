@@ -0,0 +1,29 @@
+pub fn play_fish_game(mut grouped_fish: [usize; 9], number_of_days: usize) -> usize {
+    for _ in 0..number_of_days {
+        let new_fish = grouped_fish[0];
+
+        for i in 1..grouped_fish.len() {
+            grouped_fish[i - 1] = grouped_fish[i];
+        }
+
+        grouped_fish[8] = new_fish;
+        grouped_fish[6] += new_fish;
+    }
+
+    grouped_fish.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_06_lanternfish::parse;
+    use crate::year_2021::day_06_lanternfish::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let grouped_lanternfish = parse(&read_example("06.txt").unwrap()).unwrap();
+        assert_eq!(grouped_lanternfish.iter().sum::<usize>(), 5);
+        let result = play_fish_game(grouped_lanternfish, 80);
+        assert_eq!(result, 5934);
+    }
+}
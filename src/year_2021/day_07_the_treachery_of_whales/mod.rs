@@ -0,0 +1,31 @@
+use crate::answer::Answer;
+use crate::parse::{integers, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Vec<u16>, ParseError> {
+    integers(input, input, "a crab position")
+}
+
+pub struct TheTreacheryOfWhales;
+
+impl Solution for TheTreacheryOfWhales {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input = Vec<u16>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(crab_positions: &Self::Input) -> Answer {
+        part_1::calculate_fuel_consumption(crab_positions).into()
+    }
+
+    fn part_2(crab_positions: &Self::Input) -> Answer {
+        part_2::calculate_fuel_consumption(crab_positions).into()
+    }
+}
@@ -0,0 +1,24 @@
+pub fn calculate_fuel_consumption(crab_positions: &[u16]) -> usize {
+    let mut crab_positions = crab_positions.to_vec();
+    crab_positions.sort();
+    let median_pos = crab_positions[crab_positions.len() / 2];
+    crab_positions
+        .iter()
+        .map(|crab_pos| crab_pos.abs_diff(median_pos) as usize)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_07_the_treachery_of_whales::parse;
+    use crate::year_2021::day_07_the_treachery_of_whales::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let crab_positions: Vec<u16> = parse(&read_example("07.txt").unwrap()).unwrap();
+        assert_eq!(crab_positions.len(), 10);
+        let result = calculate_fuel_consumption(&crab_positions);
+        assert_eq!(result, 37)
+    }
+}
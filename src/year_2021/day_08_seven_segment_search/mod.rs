@@ -0,0 +1,63 @@
+use crate::answer::Answer;
+use crate::parse::{only_chars, split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Unique signal patterns and the four digit output value of a display.
+pub struct Entry {
+    pub patterns: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let segments = |text: &str, count: usize| {
+        let digits = text
+            .split_whitespace()
+            .map(|digit| only_chars(input, digit, "abcdefg", "a segment from a to g"))
+            .map(|digit| digit.map(ToString::to_string))
+            .collect::<Result<Vec<String>, _>>()?;
+
+        match digits.len() == count {
+            true => Ok(digits),
+            false => Err(ParseError::at(
+                input,
+                text,
+                format!("expected {count} digits"),
+            )),
+        }
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (signal_patterns, digit_outputs) = split_once(input, line, " | ")?;
+            Ok(Entry {
+                patterns: segments(signal_patterns, 10)?,
+                outputs: segments(digit_outputs, 4)?,
+            })
+        })
+        .collect()
+}
+
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(entries: &Self::Input) -> Answer {
+        part_1::count_occurrence_of_certain_digits(entries).into()
+    }
+
+    fn part_2(entries: &Self::Input) -> Answer {
+        part_2::calculate_output_values_sum(entries).into()
+    }
+}
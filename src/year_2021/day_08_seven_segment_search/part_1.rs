@@ -0,0 +1,24 @@
+use crate::year_2021::day_08_seven_segment_search::Entry;
+
+pub fn count_occurrence_of_certain_digits(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| &entry.outputs)
+        .filter(|digit| matches!(digit.len(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_08_seven_segment_search::parse;
+    use crate::year_2021::day_08_seven_segment_search::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let entries = parse(&read_example("08.txt").unwrap()).unwrap();
+        assert_eq!(entries.len(), 10);
+        let result = count_occurrence_of_certain_digits(&entries);
+        assert_eq!(result, 26);
+    }
+}
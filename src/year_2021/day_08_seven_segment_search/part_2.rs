@@ -1,30 +1,13 @@
-fn process_data(path: &str) -> Vec<(Vec<String>, Vec<String>)> {
-    let file_content = std::fs::read_to_string(path).unwrap();
+use crate::year_2021::day_08_seven_segment_search::Entry;
 
-    file_content
-        .lines()
-        .map(|line| {
-            let (signal_patterns, digit_outputs) = line.split_once(" | ").unwrap();
-            (
-                signal_patterns
-                    .split_whitespace()
-                    .map(ToString::to_string)
-                    .collect(),
-                digit_outputs
-                    .split_whitespace()
-                    .map(ToString::to_string)
-                    .collect(),
-            )
-        })
-        .collect()
-}
-
-fn calculate_output_values_sum(
-    sets_of_patterns_and_outputs: &[(Vec<String>, Vec<String>)],
-) -> usize {
+pub fn calculate_output_values_sum(entries: &[Entry]) -> usize {
     let mut sum_of_output_values = 0usize;
 
-    for (patterns, digit_outputs) in sets_of_patterns_and_outputs {
+    for Entry {
+        patterns,
+        outputs: digit_outputs,
+    } in entries
+    {
         let pattern_of_one = patterns.iter().find(|&pattern| pattern.len() == 2).unwrap();
         let pattern_of_four = patterns.iter().find(|&pattern| pattern.len() == 4).unwrap();
         let mut decoded_digit_output_values: String = String::new();
@@ -63,21 +46,17 @@ fn calculate_output_values_sum(
     sum_of_output_values
 }
 
-pub fn solve() -> String {
-    let signal_patterns_and_output = process_data("./input/08.txt");
-    let result = calculate_output_values_sum(&signal_patterns_and_output);
-    format!("Day 8: Seven Segment Search (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_08_seven_segment_search::part_2::*;
+    use crate::year_2021::day_08_seven_segment_search::parse;
+    use crate::year_2021::day_08_seven_segment_search::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let lit_digits = process_data("./test_input/08.txt");
-        assert_eq!(lit_digits.len(), 10);
-        let result = calculate_output_values_sum(&lit_digits);
+        let entries = parse(&read_example("08.txt").unwrap()).unwrap();
+        assert_eq!(entries.len(), 10);
+        let result = calculate_output_values_sum(&entries);
         assert_eq!(result, 61229);
     }
 }
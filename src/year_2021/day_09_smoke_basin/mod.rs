@@ -0,0 +1,34 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, input, "a height", |c| {
+        c.to_digit(10).map(|height| height as u8)
+    })
+}
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(heightmap: &Self::Input) -> Answer {
+        part_1::calculate_sum_of_risk_levels_of_low_points(heightmap).into()
+    }
+
+    fn part_2(heightmap: &Self::Input) -> Answer {
+        part_2::calculate_sizes_of_three_largest_basins(heightmap).into()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::grid::Grid;
+
+pub fn calculate_sum_of_risk_levels_of_low_points(heightmap: &Grid<u8>) -> usize {
+    let mut sum_of_risk_levels_of_low_points = 0usize;
+
+    for (position, &height) in heightmap.iter() {
+        let is_not_low = heightmap
+            .neighbours_4(position)
+            .any(|neighbour| height >= heightmap[neighbour]);
+
+        if !is_not_low {
+            sum_of_risk_levels_of_low_points += height as usize + 1
+        }
+    }
+
+    sum_of_risk_levels_of_low_points
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_09_smoke_basin::parse;
+    use crate::year_2021::day_09_smoke_basin::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let heightmap = parse(&read_example("09.txt").unwrap()).unwrap();
+        assert_eq!(heightmap.height(), 5);
+        let result = calculate_sum_of_risk_levels_of_low_points(&heightmap);
+        assert_eq!(result, 15)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::grid::Grid;
+
+/// Height marking a location already counted in a basin.
+const VISITED: u8 = u8::MAX;
+
+fn calculate_basin_size(heightmap: &mut Grid<u8>, start: (usize, usize)) -> usize {
+    let mut local_basin: Vec<(usize, usize)> = Vec::new();
+    local_basin.push(start);
+    heightmap[start] = VISITED;
+    let mut basin_size: usize = 1;
+
+    while let Some(position) = local_basin.pop() {
+        let neighbours: Vec<(usize, usize)> = heightmap.neighbours_4(position).collect();
+
+        for neighbour in neighbours {
+            if heightmap[neighbour] != 9 && heightmap[neighbour] != VISITED {
+                local_basin.push(neighbour);
+                heightmap[neighbour] = VISITED;
+                basin_size += 1;
+            }
+        }
+    }
+
+    basin_size
+}
+
+pub fn calculate_sizes_of_three_largest_basins(heightmap: &Grid<u8>) -> usize {
+    let mut heightmap = heightmap.clone();
+    let mut basin_sizes = Vec::new();
+
+    for position in heightmap.positions().collect::<Vec<_>>() {
+        if heightmap[position] != 9 && heightmap[position] != VISITED {
+            let basin_size = calculate_basin_size(&mut heightmap, position);
+            basin_sizes.push(basin_size);
+        }
+    }
+
+    basin_sizes.sort();
+    basin_sizes.iter().rev().take(3).product::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_09_smoke_basin::parse;
+    use crate::year_2021::day_09_smoke_basin::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let heightmap = parse(&read_example("09.txt").unwrap()).unwrap();
+        assert_eq!(heightmap.height(), 5);
+        let result = calculate_sizes_of_three_largest_basins(&heightmap);
+        assert_eq!(result, 1134)
+    }
+}
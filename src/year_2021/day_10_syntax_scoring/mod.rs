@@ -0,0 +1,42 @@
+use crate::answer::Answer;
+use crate::parse::{only_chars, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<String> = input
+        .lines()
+        .map(|line| only_chars(input, line, "()[]{}<>", "a bracket").map(str::to_owned))
+        .collect::<Result<_, _>>()?;
+
+    match lines.is_empty() {
+        true => Err(ParseError::end_of_input(
+            input,
+            "expected a line of brackets",
+        )),
+        false => Ok(lines),
+    }
+}
+
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(log: &Self::Input) -> Answer {
+        part_1::calculate_syntax_error_score(log).into()
+    }
+
+    fn part_2(log: &Self::Input) -> Answer {
+        part_2::calculate_autocomplete_score(log).into()
+    }
+}
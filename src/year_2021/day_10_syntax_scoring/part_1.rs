@@ -1,14 +1,5 @@
 use std::collections::HashMap;
 
-fn process_data(path: &str) -> Vec<String> {
-    let file_content = std::fs::read_to_string(path);
-    file_content
-        .unwrap()
-        .lines()
-        .map(|line| line.to_owned())
-        .collect()
-}
-
 fn find_syntax_error(line: &str) -> Option<char> {
     let symbol_pairs: HashMap<char, char> =
         HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
@@ -29,7 +20,7 @@ fn find_syntax_error(line: &str) -> Option<char> {
     None
 }
 
-fn calculate_syntax_error_score(log: &[String]) -> usize {
+pub fn calculate_syntax_error_score(log: &[String]) -> usize {
     let score_map: HashMap<char, usize> =
         HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
 
@@ -38,19 +29,15 @@ fn calculate_syntax_error_score(log: &[String]) -> usize {
         .sum()
 }
 
-pub fn solve() -> String {
-    let log = process_data("./input/10.txt");
-    let result = calculate_syntax_error_score(&log);
-    format!("Day 10: Syntax Scoring (Part 1) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_10_syntax_scoring::part_1::*;
+    use crate::year_2021::day_10_syntax_scoring::parse;
+    use crate::year_2021::day_10_syntax_scoring::part_1::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let log = process_data("./test_input/10.txt");
+        let log = parse(&read_example("10.txt").unwrap()).unwrap();
         assert_eq!(log.len(), 10);
         let result = calculate_syntax_error_score(&log);
         assert_eq!(result, 26397);
@@ -1,10 +1,5 @@
 use std::collections::HashMap;
 
-fn process_data(path: &str) -> Vec<String> {
-    let file_content = std::fs::read_to_string(path);
-    file_content.unwrap().lines().map(str::to_owned).collect()
-}
-
 fn find_missing_symbols(line: &str) -> Option<Vec<char>> {
     let symbol_pairs: HashMap<char, char> =
         HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
@@ -26,7 +21,7 @@ fn find_missing_symbols(line: &str) -> Option<Vec<char>> {
     Some(stack)
 }
 
-fn calculate_autocomplete_score(log: &[String]) -> usize {
+pub fn calculate_autocomplete_score(log: &[String]) -> usize {
     let score_map: HashMap<char, usize> = HashMap::from([(')', 1), (']', 2), ('}', 3), ('>', 4)]);
 
     let mut autocomplete_scores: Vec<usize> = log
@@ -41,19 +36,15 @@ fn calculate_autocomplete_score(log: &[String]) -> usize {
     autocomplete_scores[autocomplete_scores.len() / 2]
 }
 
-pub fn solve() -> String {
-    let log = process_data("./input/10.txt");
-    let result = calculate_autocomplete_score(&log);
-    format!("Day 10: Syntax Scoring (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_10_syntax_scoring::part_2::*;
+    use crate::year_2021::day_10_syntax_scoring::parse;
+    use crate::year_2021::day_10_syntax_scoring::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let log = process_data("./test_input/10.txt");
+        let log = parse(&read_example("10.txt").unwrap()).unwrap();
         assert_eq!(log.len(), 10);
         let result = calculate_autocomplete_score(&log);
         assert_eq!(result, 288957);
@@ -0,0 +1,32 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input, input)
+}
+
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(energy_level_of_octopuses: &Self::Input) -> Answer {
+        part_1::play_game(&mut energy_level_of_octopuses.clone(), 100).into()
+    }
+
+    fn part_2(energy_level_of_octopuses: &Self::Input) -> Answer {
+        part_2::play_game(&mut energy_level_of_octopuses.clone()).into()
+    }
+}
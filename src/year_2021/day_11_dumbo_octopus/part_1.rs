@@ -0,0 +1,57 @@
+use crate::grid::Grid;
+
+fn increment(
+    position: (usize, usize),
+    octopuses: &mut Grid<u8>,
+    flashed: &mut Grid<bool>,
+) -> usize {
+    if flashed[position] {
+        return 0;
+    }
+
+    octopuses[position] += 1;
+
+    if octopuses[position] > 9 {
+        octopuses[position] = 0;
+        flashed[position] = true;
+        let mut flash_counter: usize = 1;
+        let neighbours: Vec<(usize, usize)> = octopuses.neighbours_8(position).collect();
+
+        for neighbour in neighbours {
+            flash_counter += increment(neighbour, octopuses, flashed);
+        }
+
+        return flash_counter;
+    }
+
+    0
+}
+
+pub fn play_game(octopuses: &mut Grid<u8>, number_of_rounds: usize) -> usize {
+    let positions: Vec<(usize, usize)> = octopuses.positions().collect();
+    let mut flash_counter: usize = 0;
+
+    for _ in 0..number_of_rounds {
+        let mut flashed = Grid::new(octopuses.width(), octopuses.height(), false);
+
+        for &position in &positions {
+            flash_counter += increment(position, octopuses, &mut flashed);
+        }
+    }
+
+    flash_counter
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_11_dumbo_octopus::parse;
+    use crate::year_2021::day_11_dumbo_octopus::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let mut energy_level_of_octopuses = parse(&read_example("11.txt").unwrap()).unwrap();
+        let result = play_game(&mut energy_level_of_octopuses, 100);
+        assert_eq!(result, 1656);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::grid::Grid;
+
+fn increment(position: (usize, usize), octopuses: &mut Grid<u8>, flashed: &mut Grid<bool>) {
+    if flashed[position] {
+        return;
+    }
+
+    octopuses[position] += 1;
+
+    if octopuses[position] > 9 {
+        octopuses[position] = 0;
+        flashed[position] = true;
+        let neighbours: Vec<(usize, usize)> = octopuses.neighbours_8(position).collect();
+
+        for neighbour in neighbours {
+            increment(neighbour, octopuses, flashed);
+        }
+    }
+}
+
+pub fn play_game(octopuses: &mut Grid<u8>) -> usize {
+    let positions: Vec<(usize, usize)> = octopuses.positions().collect();
+    let mut round: usize = 0;
+
+    loop {
+        round += 1;
+        let mut flashed = Grid::new(octopuses.width(), octopuses.height(), false);
+
+        for &position in &positions {
+            increment(position, octopuses, &mut flashed);
+        }
+
+        if flashed.values().all(|&value| value) {
+            break round;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_11_dumbo_octopus::parse;
+    use crate::year_2021::day_11_dumbo_octopus::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let mut energy_level_of_octopuses = parse(&read_example("11.txt").unwrap()).unwrap();
+        let result = play_game(&mut energy_level_of_octopuses);
+        assert_eq!(result, 195);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::answer::Answer;
+use crate::parse::{split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+#[derive(PartialEq)]
+enum CaveKind {
+    Small,
+    Big,
+}
+
+pub struct Cave {
+    pub name: String,
+    pub id: usize,
+    kind: CaveKind,
+    neighbours: Vec<usize>,
+}
+
+impl Cave {
+    fn new(name: &str, id: usize) -> Self {
+        Self {
+            name: name.to_owned(),
+            id,
+            kind: match name.chars().all(char::is_uppercase) {
+                false => CaveKind::Small,
+                true => CaveKind::Big,
+            },
+            neighbours: Vec::new(),
+        }
+    }
+}
+
+fn get_id_or_create_and_add_cave(name: &str, caves: &mut Vec<Cave>) -> usize {
+    match caves.iter().find(|cave| cave.name == name) {
+        Some(cave) => cave.id,
+        None => {
+            let next_id = caves.len();
+            caves.push(Cave::new(name, next_id));
+            next_id
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cave>, ParseError> {
+    let mut caves: Vec<Cave> = Vec::new();
+
+    for line in input.lines() {
+        let (cave_from_name, cave_to_name) = split_once(input, line, "-")?;
+        let cave_from_id = get_id_or_create_and_add_cave(cave_from_name, &mut caves);
+        let cave_to_id = get_id_or_create_and_add_cave(cave_to_name, &mut caves);
+        caves[cave_from_id].neighbours.push(cave_to_id);
+        caves[cave_to_id].neighbours.push(cave_from_id);
+    }
+
+    for name in ["start", "end"] {
+        if !caves.iter().any(|cave| cave.name == name) {
+            return Err(ParseError::end_of_input(
+                input,
+                format!("expected a connection to the {name} cave"),
+            ));
+        }
+    }
+
+    Ok(caves)
+}
+
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input = Vec<Cave>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(caves: &Self::Input) -> Answer {
+        let mut visited: Vec<usize> = Vec::new();
+        let start_id = &caves.iter().find(|cave| cave.name == "start").unwrap().id;
+        let end_id = &caves.iter().find(|cave| cave.name == "end").unwrap().id;
+        part_1::count_paths(start_id, end_id, &mut visited, caves).into()
+    }
+
+    fn part_2(caves: &Self::Input) -> Answer {
+        let mut visited: Vec<usize> = Vec::new();
+        let start_id = &caves.iter().find(|cave| cave.name == "start").unwrap().id;
+        let end_id = &caves.iter().find(|cave| cave.name == "end").unwrap().id;
+        part_2::count_paths(start_id, start_id, end_id, &mut visited, caves, false).into()
+    }
+}
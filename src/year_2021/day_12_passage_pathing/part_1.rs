@@ -0,0 +1,45 @@
+use crate::year_2021::day_12_passage_pathing::{Cave, CaveKind};
+
+pub fn count_paths(
+    current_cave_id: &usize,
+    end: &usize,
+    visited: &mut Vec<usize>,
+    caves: &[Cave],
+) -> usize {
+    if current_cave_id == end {
+        return 1;
+    }
+
+    if caves[*current_cave_id].kind == CaveKind::Small && visited.contains(current_cave_id) {
+        return 0;
+    }
+
+    visited.push(*current_cave_id);
+
+    let path_counter: usize = caves[*current_cave_id]
+        .neighbours
+        .iter()
+        .map(|cave_id| count_paths(cave_id, end, visited, caves))
+        .sum();
+
+    visited.pop();
+    path_counter
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_12_passage_pathing::parse;
+    use crate::year_2021::day_12_passage_pathing::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let caves = parse(&read_example("12.txt").unwrap()).unwrap();
+        assert_eq!(caves.len(), 6);
+        let mut visited: Vec<usize> = Vec::new();
+        let start_id = &caves.iter().find(|cave| cave.name == "start").unwrap().id;
+        let end_id = &caves.iter().find(|cave| cave.name == "end").unwrap().id;
+        let result = count_paths(start_id, end_id, &mut visited, &caves);
+        assert_eq!(result, 10);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::answer::Answer;
+use crate::parse::{pair, parse_as, section_pair, split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point(usize, usize);
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+/// Dots on the transparent paper and the fold instructions, of which there is at least one.
+pub struct Manual {
+    pub dots: Vec<Point>,
+    pub instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Result<Manual, ParseError> {
+    let (dots, instructions) = section_pair(input, input, "fold instructions")?;
+
+    Ok(Manual {
+        dots: dots
+            .lines()
+            .map(|line| pair(input, line, ",", "a coordinate").map(|(x, y)| Point(x, y)))
+            .collect::<Result<_, _>>()?,
+        instructions: instructions
+            .lines()
+            .map(|line| parse_instruction(input, line))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn parse_instruction(input: &str, line: &str) -> Result<Instruction, ParseError> {
+    let (text, value) = split_once(input, line, "=")?;
+    let value: usize = parse_as(input, value, "a fold line")?;
+
+    match text.strip_prefix("fold along ") {
+        Some("x") => Ok(Instruction::Vertical(value)),
+        Some("y") => Ok(Instruction::Horizontal(value)),
+        _ => Err(ParseError::at(
+            input,
+            text,
+            format!("expected \"fold along x\" or \"fold along y\", found {text:?}"),
+        )),
+    }
+}
+
+pub struct TransparentOrigami;
+
+impl Solution for TransparentOrigami {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(manual: &Self::Input) -> Answer {
+        part_1::fold_once(&manual.dots, manual.instructions[0]).into()
+    }
+
+    fn part_2(manual: &Self::Input) -> Answer {
+        let folded_paper = part_2::fold(&manual.dots, &manual.instructions);
+        Answer::Grid(part_2::show_letters(&folded_paper))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::year_2021::day_13_transparent_origami::{Instruction, Point};
+use std::collections::HashSet;
+
+pub fn fold_once(paper: &[Point], instruction: Instruction) -> usize {
+    paper
+        .iter()
+        .map(|&Point(x, y)| match instruction {
+            Instruction::Vertical(col) => Point(if x < col { x } else { 2 * col - x }, y),
+            Instruction::Horizontal(row) => Point(x, if y < row { y } else { 2 * row - y }),
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_13_transparent_origami::parse;
+    use crate::year_2021::day_13_transparent_origami::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let manual = parse(&read_example("13.txt").unwrap()).unwrap();
+        assert_eq!(manual.dots.len(), 18);
+        let result = fold_once(&manual.dots, manual.instructions[0]);
+        assert_eq!(result, 17);
+    }
+}
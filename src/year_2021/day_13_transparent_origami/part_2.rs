@@ -0,0 +1,60 @@
+use crate::year_2021::day_13_transparent_origami::{Instruction, Point};
+
+pub fn fold(paper: &[Point], instructions: &[Instruction]) -> Vec<Point> {
+    let mut paper = paper.to_vec();
+
+    for instruction in instructions {
+        paper = paper
+            .into_iter()
+            .map(|Point(x, y)| match instruction {
+                Instruction::Vertical(col) => Point(if x < *col { x } else { 2 * col - x }, y),
+                Instruction::Horizontal(row) => Point(x, if y < *row { y } else { 2 * row - y }),
+            })
+            .collect::<Vec<_>>();
+    }
+
+    paper.sort();
+    paper.dedup();
+    paper
+}
+
+pub fn show_letters(paper: &[Point]) -> Vec<String> {
+    let max_x = paper.iter().map(|Point(x, _)| x).max().unwrap();
+    let max_y = paper.iter().map(|Point(_, y)| y).max().unwrap();
+    let mut output: Vec<String> = Vec::new();
+
+    for y in 0..=*max_y {
+        let mut row = String::new();
+
+        for x in 0..=*max_x {
+            if paper.contains(&Point(x, y)) {
+                row.push('█');
+            } else {
+                row.push(' ');
+            }
+        }
+
+        output.push(row);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_13_transparent_origami::parse;
+    use crate::year_2021::day_13_transparent_origami::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let manual = parse(&read_example("13.txt").unwrap()).unwrap();
+        assert_eq!(manual.dots.len(), 18);
+        let folded_paper = fold(&manual.dots, &manual.instructions);
+        assert_eq!(folded_paper.len(), 16);
+        let letters = show_letters(&folded_paper);
+        assert_eq!(letters.len(), 5);
+        assert_eq!(letters[0], "█████");
+        assert_eq!(letters[1], "█   █");
+    }
+}
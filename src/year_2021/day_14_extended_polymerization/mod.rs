@@ -0,0 +1,70 @@
+use crate::answer::Answer;
+use crate::parse::{section_pair, split_once, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub mod part_1;
+pub mod part_2;
+
+pub type Rules = HashMap<(char, char), char>;
+
+/// Polymer template and the pair insertion rules applied to it in each step.
+pub struct Formula {
+    pub template: String,
+    pub rules: Rules,
+}
+
+pub fn parse(input: &str) -> Result<Formula, ParseError> {
+    let (template, rules) = section_pair(input, input, "pair insertion rules")?;
+    let rules = rules
+        .lines()
+        .map(|line| parse_rule(input, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Formula {
+        template: template.to_owned(),
+        rules,
+    })
+}
+
+fn parse_rule(input: &str, line: &str) -> Result<((char, char), char), ParseError> {
+    let (char_pair, insert) = split_once(input, line, " -> ")?;
+    let char_pair: Vec<char> = char_pair.chars().collect();
+    let insert: Vec<char> = insert.chars().collect();
+
+    match (&char_pair[..], &insert[..]) {
+        (&[first_char, second_char], &[insert_char]) => {
+            Ok(((first_char, second_char), insert_char))
+        }
+        _ => Err(ParseError::at(
+            input,
+            line,
+            format!("expected a pair of elements and an element to insert, found {line:?}"),
+        )),
+    }
+}
+
+pub struct ExtendedPolymerization;
+
+impl Solution for ExtendedPolymerization {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    type Input = Formula;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(formula: &Self::Input) -> Answer {
+        part_1::grow_polymer(&formula.template, &formula.rules).into()
+    }
+
+    fn part_2(formula: &Self::Input) -> Answer {
+        let mut polymer_pair_form = part_2::count_pairs(&formula.template);
+        part_2::grow_polymer(&mut polymer_pair_form, &formula.rules, 40);
+        let char_counters =
+            part_2::count_each_char_occurrence(&formula.template, &polymer_pair_form);
+        part_2::calculate_score_from_min_and_max_value(&char_counters).into()
+    }
+}
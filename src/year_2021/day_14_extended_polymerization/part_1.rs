@@ -0,0 +1,49 @@
+use crate::year_2021::day_14_extended_polymerization::Rules;
+use std::collections::HashMap;
+
+pub fn grow_polymer(template: &str, rules: &Rules) -> usize {
+    let mut polymer: Vec<char> = template.chars().collect();
+
+    for _ in 0..10 {
+        let mut next_polymer: Vec<char> = Vec::new();
+
+        polymer.windows(2).for_each(|pair| {
+            let first_char = pair.first().unwrap();
+            let second_char = pair.last().unwrap();
+            next_polymer.push(*first_char);
+            next_polymer.push(rules[&(*first_char, *second_char)]);
+        });
+        next_polymer.push(*polymer.last().unwrap());
+        polymer = next_polymer;
+    }
+
+    let mut counters = HashMap::new();
+
+    for &char in &polymer {
+        *counters.entry(char).or_insert(0) += 1;
+    }
+
+    let (min, max) = counters
+        .values()
+        .fold((usize::MAX, 0), |(min, max), &count| {
+            (min.min(count), max.max(count))
+        });
+
+    max - min
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_14_extended_polymerization::parse;
+    use crate::year_2021::day_14_extended_polymerization::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let formula = parse(&read_example("14.txt").unwrap()).unwrap();
+        assert_eq!(formula.template.len(), 4);
+        assert_eq!(formula.rules.len(), 16);
+        let result = grow_polymer(&formula.template, &formula.rules);
+        assert_eq!(result, 1588);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::year_2021::day_14_extended_polymerization::Rules;
+use std::collections::HashMap;
+
+pub fn count_pairs(polymer_original_form: &str) -> HashMap<(char, char), usize> {
+    polymer_original_form
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(2)
+        .fold(HashMap::new(), |mut acc, pair| {
+            let first_char = pair.first().unwrap();
+            let second_char = pair.last().unwrap();
+            *acc.entry((*first_char, *second_char)).or_insert(0) += 1;
+            acc
+        })
+}
+
+pub fn grow_polymer(
+    polymer_pair_form: &mut HashMap<(char, char), usize>,
+    insertion_rules: &Rules,
+    number_of_rounds: usize,
+) {
+    for _ in 0..number_of_rounds {
+        let mut next_polymer_pair_form: HashMap<(char, char), usize> = HashMap::new();
+
+        for (&(first_char, second_char), &counter) in polymer_pair_form.iter() {
+            let insert_char = insertion_rules
+                .get(&(first_char, second_char))
+                .expect("Insertion rule should exist for every pair");
+
+            *next_polymer_pair_form
+                .entry((first_char, *insert_char))
+                .or_insert(0) += counter;
+
+            *next_polymer_pair_form
+                .entry((*insert_char, second_char))
+                .or_insert(0) += counter;
+        }
+
+        *polymer_pair_form = next_polymer_pair_form;
+    }
+}
+
+pub fn count_each_char_occurrence(
+    polymer_original_form: &str,
+    polymer_pair_form: &HashMap<(char, char), usize>,
+) -> HashMap<char, usize> {
+    let original_polymer_first_char = polymer_original_form.chars().next().unwrap();
+    let mut char_counters: HashMap<char, usize> = HashMap::from([(original_polymer_first_char, 1)]);
+
+    for ((_, second_char), &counter) in polymer_pair_form {
+        *char_counters.entry(*second_char).or_insert(0) += counter;
+    }
+
+    char_counters
+}
+
+pub fn calculate_score_from_min_and_max_value(char_counters: &HashMap<char, usize>) -> usize {
+    let (min, max) = char_counters
+        .values()
+        .fold((usize::MAX, 0), |(min, max), &counter| {
+            (min.min(counter), max.max(counter))
+        });
+
+    max - min
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_14_extended_polymerization::parse;
+    use crate::year_2021::day_14_extended_polymerization::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let formula = parse(&read_example("14.txt").unwrap()).unwrap();
+        let mut polymer_pair_form = count_pairs(&formula.template);
+        assert_eq!(polymer_pair_form.len(), 3);
+        assert_eq!(formula.rules.len(), 16);
+        grow_polymer(&mut polymer_pair_form, &formula.rules, 40);
+        assert_eq!(polymer_pair_form.len(), 15);
+        let char_counters = count_each_char_occurrence(&formula.template, &polymer_pair_form);
+        assert_eq!(char_counters.len(), 4);
+        let result = calculate_score_from_min_and_max_value(&char_counters);
+        assert_eq!(result, 2188189693529);
+    }
+}
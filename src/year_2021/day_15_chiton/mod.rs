@@ -0,0 +1,35 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input, input)
+}
+
+pub struct Chiton;
+
+impl Solution for Chiton {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(cavern: &Self::Input) -> Answer {
+        part_1::find_the_shortest_path(cavern).into()
+    }
+
+    fn part_2(cavern: &Self::Input) -> Answer {
+        let cavern = part_2::expand_cavern(cavern);
+        let start: (usize, usize) = (0, 0);
+        let end = (cavern.width() - 1, cavern.height() - 1);
+        part_2::find_the_shortest_path(&cavern, start, end).into()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::grid::Grid;
+use crate::search::dijkstra;
+
+pub fn find_the_shortest_path(cavern: &Grid<u8>) -> usize {
+    let end = (cavern.width() - 1, cavern.height() - 1);
+    let search = dijkstra(
+        (0, 0),
+        |&position| {
+            cavern
+                .neighbours_4(position)
+                .map(move |neighbour| (neighbour, cavern[neighbour] as usize))
+        },
+        |&position| position == end,
+    );
+
+    search.path.expect("The end should be reachable").cost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_15_chiton::parse;
+    use crate::year_2021::day_15_chiton::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let cavern = parse(&read_example("15.txt").unwrap()).unwrap();
+        assert_eq!(cavern.height(), 10);
+        let result = find_the_shortest_path(&cavern);
+        assert_eq!(result, 40);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::grid::Grid;
+use crate::search::a_star;
+
+/// Repeats the cavern five times in each direction, increasing the risk level of each repetition.
+pub fn expand_cavern(cavern: &Grid<u8>) -> Grid<u8> {
+    let (width, height) = (cavern.width(), cavern.height());
+
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        (cavern[(x % width, y % height)] + (x / width + y / height) as u8 - 1) % 9 + 1
+    })
+}
+
+/// Every risk level is at least 1, so the Manhattan distance to the end never overestimates the
+/// risk left.
+pub fn find_the_shortest_path(
+    cavern: &Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> isize {
+    let search = a_star(
+        start,
+        |&position| {
+            cavern
+                .neighbours_4(position)
+                .map(move |neighbour| (neighbour, cavern[neighbour] as isize))
+        },
+        |&position| position == end,
+        |&(x, y)| (x.abs_diff(end.0) + y.abs_diff(end.1)) as isize,
+    );
+
+    search.path.expect("The end should be reachable").cost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_15_chiton::parse;
+    use crate::year_2021::day_15_chiton::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let cavern = expand_cavern(&parse(&read_example("15.txt").unwrap()).unwrap());
+        assert_eq!(cavern.height(), 50);
+        let start: (usize, usize) = (0, 0);
+        let end = (cavern.width() - 1, cavern.height() - 1);
+        let result = find_the_shortest_path(&cavern, start, end);
+        assert_eq!(result, 315);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::answer::Answer;
+use crate::parse::{only_chars, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses the hexadecimal transmission into its binary form.
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let transmission = only_chars(
+        input,
+        input.trim_end(),
+        "0123456789ABCDEFabcdef",
+        "a hexadecimal digit",
+    )?;
+
+    if transmission.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected a transmission"));
+    }
+
+    Ok(convert_hexadecimal_sequence_into_binary(transmission))
+}
+
+pub fn convert_hexadecimal_sequence_into_binary(hexadecimal_sequence: &str) -> String {
+    hexadecimal_sequence
+        .chars()
+        .fold(String::new(), |mut acc, c| {
+            let value = c.to_digit(16).expect("Should be hexadecimal character");
+            use std::fmt::Write;
+            write!(&mut acc, "{:04b}", value).unwrap();
+            acc
+        })
+}
+
+pub struct PacketDecoder;
+
+impl Solution for PacketDecoder {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(binary_sequence: &Self::Input) -> Answer {
+        part_1::parse_packet(&mut binary_sequence.clone()).into()
+    }
+
+    fn part_2(binary_sequence: &Self::Input) -> Answer {
+        part_2::parse_packet(&mut binary_sequence.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_16_packet_decoder::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn read_from_file_to_string() {
+        let binary_sequence = parse(&read_example("16.txt").unwrap()).unwrap();
+        assert_eq!(binary_sequence.len(), 30 * 4);
+        assert!(binary_sequence.starts_with("1010000000000001"));
+    }
+
+    #[test]
+    fn convert_hexadecimal_string_sequence_into_binary_string_sequence() {
+        let binary_sequence = convert_hexadecimal_sequence_into_binary("D2FE28");
+        assert_eq!(binary_sequence, "110100101111111000101000");
+    }
+}
@@ -1,20 +1,9 @@
-fn process_data(path: &str) -> String {
-    let file_content = std::fs::read_to_string(path).unwrap();
-
-    file_content.chars().fold(String::new(), |mut acc, c| {
-        let value = c.to_digit(16).expect("Should be hexadecimal character");
-        use std::fmt::Write;
-        write!(&mut acc, "{:04b}", value).unwrap();
-        acc
-    })
-}
-
 fn binary_to_decimal(packet: &mut String, length: usize) -> usize {
     let version: String = packet.drain(..length).collect();
     usize::from_str_radix(&version, 2).expect("Should be valid binary string")
 }
 
-fn parse_packet(packet: &mut String) -> usize {
+pub fn parse_packet(packet: &mut String) -> usize {
     let mut total_version = binary_to_decimal(packet, 3);
     let packet_type_id = binary_to_decimal(packet, 3);
 
@@ -51,19 +40,15 @@ fn parse_packet(packet: &mut String) -> usize {
     total_version
 }
 
-pub fn solve() -> String {
-    let mut binary_sequence = process_data("./input/16.txt");
-    let result = parse_packet(&mut binary_sequence);
-    format!("Day 16: Packet Decoder (Part 1) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_16_packet_decoder::part_1::*;
+    use crate::year_2021::day_16_packet_decoder::parse;
+    use crate::year_2021::day_16_packet_decoder::part_1::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let mut binary_sequence = process_data("./test_input/16.txt");
+        let mut binary_sequence = parse(&read_example("16.txt").unwrap()).unwrap();
         let result = parse_packet(&mut binary_sequence);
         assert_eq!(result, 31);
     }
@@ -18,21 +18,6 @@ impl LengthTypeId {
     const NUMBER_OF_SUB_PACKETS_STORED_IN_11_BITS: usize = 1;
 }
 
-fn process_data(path: &str) -> String {
-    std::fs::read_to_string(path).unwrap()
-}
-
-fn convert_hexadecimal_sequence_into_binary(hexadecimal_sequence: &str) -> String {
-    hexadecimal_sequence
-        .chars()
-        .fold(String::new(), |mut acc, c| {
-            let value = c.to_digit(16).expect("Should be hexadecimal character");
-            use std::fmt::Write;
-            write!(&mut acc, "{:04b}", value).unwrap();
-            acc
-        })
-}
-
 fn binary_to_decimal(packet: &mut String, length: usize) -> usize {
     let version: String = packet.drain(..length).collect();
     usize::from_str_radix(&version, 2).expect("Should be valid binary string")
@@ -51,7 +36,7 @@ fn extract_decimal_number_from_literal_value_packet(packet: &mut String) -> usiz
     }
 }
 
-fn parse_packet(packet: &mut String) -> usize {
+pub fn parse_packet(packet: &mut String) -> usize {
     let _version = binary_to_decimal(packet, 3);
     let packet_type_id = binary_to_decimal(packet, 3);
 
@@ -90,28 +75,9 @@ fn parse_packet(packet: &mut String) -> usize {
     }
 }
 
-pub fn solve() -> String {
-    let hexadecimal_sequence = process_data("./input/16.txt");
-    let mut binary_sequence = convert_hexadecimal_sequence_into_binary(&hexadecimal_sequence);
-    let result = parse_packet(&mut binary_sequence);
-    format!("Day 16: Packet Decoder (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_16_packet_decoder::part_2::*;
-
-    #[test]
-    fn read_from_file_to_string() {
-        let hexadecimal_sequence = process_data("./test_input/16.txt");
-        assert_eq!(hexadecimal_sequence, "A0016C880162017C3686B18A3D4780");
-    }
-
-    #[test]
-    fn convert_hexadecimal_string_sequence_into_binary_string_sequence() {
-        let binary_sequence = convert_hexadecimal_sequence_into_binary("D2FE28");
-        assert_eq!(binary_sequence, "110100101111111000101000");
-    }
+    use crate::year_2021::day_16_packet_decoder::part_2::*;
 
     #[test]
     fn convert_binary_number_in_string_to_decimal_value() {
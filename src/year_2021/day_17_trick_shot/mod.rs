@@ -0,0 +1,40 @@
+use crate::answer::Answer;
+use crate::parse::{range, split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Target area as `[x1, x2, y1, y2]`.
+pub fn parse(input: &str) -> Result<[i32; 4], ParseError> {
+    let target_area = input.trim_end();
+    let ranges = target_area
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::at(input, target_area, "expected \"target area: \""))?;
+    let (x_range, y_range) = split_once(input, ranges, ", ")?;
+    let (x1, x2) = range(input, x_range, "x")?.into_inner();
+    let (y1, y2) = range(input, y_range, "y")?.into_inner();
+
+    Ok([x1, x2, y1, y2])
+}
+
+pub struct TrickShot;
+
+impl Solution for TrickShot {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Trick Shot";
+
+    type Input = [i32; 4];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&[x1, x2, y1, y2]: &Self::Input) -> Answer {
+        part_1::calculate_highest_possible_y_position(x1, x2, y1, y2).into()
+    }
+
+    fn part_2(&[x1, x2, y1, y2]: &Self::Input) -> Answer {
+        part_2::find_all_valid_velocity_pairs(x1, x2, y1, y2).into()
+    }
+}
@@ -0,0 +1,19 @@
+pub fn calculate_highest_possible_y_position(_x1: i32, _x2: i32, y1: i32, y2: i32) -> i32 {
+    let y_min = y1.min(y2);
+    -(y_min + 1) * (-(y_min + 1) + 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_17_trick_shot::parse;
+    use crate::year_2021::day_17_trick_shot::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let [x1, x2, y1, y2] = parse(&read_example("17.txt").unwrap()).unwrap();
+        assert_eq!([x1, x2, y1, y2], [20, 30, -10, -5]);
+        let result = calculate_highest_possible_y_position(x1, x2, y1, y2);
+        assert_eq!(result, 45);
+    }
+}
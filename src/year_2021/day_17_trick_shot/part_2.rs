@@ -1,20 +1,3 @@
-fn process_data(path: &str) -> [i32; 4] {
-    std::fs::read_to_string(path)
-        .expect("The input file should be placed in the input folder beforehand")
-        .trim_start_matches("target area: x=")
-        .split_once(", y=")
-        .map(|(x_values, y_values)| {
-            let (x1, x2) = x_values.split_once("..").expect("Should contain x range");
-            let (y1, y2) = y_values.split_once("..").expect("Should contain y range");
-            [x1, x2, y1, y2].map(|value| {
-                value
-                    .parse::<i32>()
-                    .expect("Should contain valid i32 values")
-            })
-        })
-        .expect("Should be exactly four i32 values")
-}
-
 fn find_valid_velocity_x_values(x1: i32, x2: i32) -> Vec<i32> {
     let mut valid_velocity_x_values = Vec::new();
 
@@ -36,7 +19,7 @@ fn find_valid_velocity_x_values(x1: i32, x2: i32) -> Vec<i32> {
     valid_velocity_x_values
 }
 
-fn find_all_valid_velocity_pairs(x1: i32, x2: i32, y1: i32, y2: i32) -> usize {
+pub fn find_all_valid_velocity_pairs(x1: i32, x2: i32, y1: i32, y2: i32) -> usize {
     let valid_velocity_x_values = find_valid_velocity_x_values(x1, x2);
     let y_min = y1.min(y2);
     let y_max = y1.max(y2);
@@ -70,19 +53,15 @@ fn find_all_valid_velocity_pairs(x1: i32, x2: i32, y1: i32, y2: i32) -> usize {
     counter
 }
 
-pub fn solve() -> String {
-    let [x1, x2, y1, y2] = process_data("./input/17.txt");
-    let result = find_all_valid_velocity_pairs(x1, x2, y1, y2);
-    format!("Day 17: Trick Shot (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_17_trick_shot::part_2::*;
+    use crate::year_2021::day_17_trick_shot::parse;
+    use crate::year_2021::day_17_trick_shot::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let [x1, x2, y1, y2] = process_data("./test_input/17.txt");
+        let [x1, x2, y1, y2] = parse(&read_example("17.txt").unwrap()).unwrap();
         assert_eq!([x1, x2, y1, y2], [20, 30, -10, -5]);
         let result = find_all_valid_velocity_pairs(x1, x2, y1, y2);
         assert_eq!(result, 112);
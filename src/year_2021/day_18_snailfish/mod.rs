@@ -0,0 +1,69 @@
+use crate::answer::Answer;
+use crate::parse::{only_chars, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses each snailfish number into its regular numbers, paired with their nesting depth.
+pub fn parse(input: &str) -> Result<Vec<Vec<(u8, usize)>>, ParseError> {
+    let mut snailfish_numbers: Vec<Vec<(u8, usize)>> = Vec::new();
+
+    for line in input.lines() {
+        let line = only_chars(input, line, "[],0123456789", "a snailfish number")?;
+        let mut depth: u8 = 0;
+        let mut snailfish_number = Vec::new();
+        for (index, c) in line.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => {
+                    depth = depth.checked_sub(1).ok_or_else(|| {
+                        ParseError::at(input, &line[index..], "unexpected closing bracket")
+                    })?
+                }
+                digit if digit.is_ascii_digit() => {
+                    let value = c.to_digit(10).unwrap() as usize;
+                    snailfish_number.push((depth, value));
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 || snailfish_number.is_empty() {
+            return Err(ParseError::at(
+                input,
+                &line[line.len()..],
+                "expected a complete snailfish number",
+            ));
+        }
+        snailfish_numbers.push(snailfish_number);
+    }
+    if snailfish_numbers.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected a snailfish number",
+        ));
+    }
+
+    Ok(snailfish_numbers)
+}
+
+pub struct Snailfish;
+
+impl Solution for Snailfish {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Snailfish";
+
+    type Input = Vec<Vec<(u8, usize)>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(snailfish_numbers: &Self::Input) -> Answer {
+        part_1::sum_up_snailfish_numbers(snailfish_numbers).into()
+    }
+
+    fn part_2(snailfish_numbers: &Self::Input) -> Answer {
+        part_2::find_highest_possible_magnitude(snailfish_numbers).into()
+    }
+}
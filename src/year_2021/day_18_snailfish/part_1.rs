@@ -1,26 +1,3 @@
-fn process_data(path: &str) -> Vec<Vec<(u8, usize)>> {
-    let file_content = std::fs::read_to_string(path).unwrap();
-    let mut snailfish_numbers: Vec<Vec<(u8, usize)>> = Vec::new();
-
-    for line in file_content.lines() {
-        let mut depth = 0;
-        let mut snailfish_number = Vec::new();
-        for c in line.chars() {
-            match c {
-                '[' => depth += 1,
-                ']' => depth -= 1,
-                digit if digit.is_ascii_digit() => {
-                    let value = c.to_digit(10).unwrap() as usize;
-                    snailfish_number.push((depth, value));
-                }
-                _ => {}
-            }
-        }
-        snailfish_numbers.push(snailfish_number);
-    }
-    snailfish_numbers
-}
-
 fn join_two_snailfish_numbers(lhs: &mut Vec<(u8, usize)>, rhs: Vec<(u8, usize)>) {
     lhs.extend(rhs);
     lhs.iter_mut().for_each(|(depth, _)| *depth += 1);
@@ -84,12 +61,11 @@ fn calculate_magnitude(snailfish_number: &mut Vec<(u8, usize)>) {
     }
 }
 
-fn sum_up_snailfish_numbers(snailfish_numbers: &mut Vec<Vec<(u8, usize)>>) -> usize {
-    let mut lhs_sfn = snailfish_numbers.remove(0);
+pub fn sum_up_snailfish_numbers(snailfish_numbers: &[Vec<(u8, usize)>]) -> usize {
+    let mut lhs_sfn = snailfish_numbers[0].clone();
 
-    while !snailfish_numbers.is_empty() {
-        let rhs_sfn = snailfish_numbers.remove(0);
-        join_two_snailfish_numbers(&mut lhs_sfn, rhs_sfn);
+    for rhs_sfn in &snailfish_numbers[1..] {
+        join_two_snailfish_numbers(&mut lhs_sfn, rhs_sfn.clone());
         while explode_snailfish_number(&mut lhs_sfn) || split_snailfish_number(&mut lhs_sfn) {}
     }
 
@@ -97,25 +73,21 @@ fn sum_up_snailfish_numbers(snailfish_numbers: &mut Vec<Vec<(u8, usize)>>) -> us
     lhs_sfn[0].1
 }
 
-pub fn solve() -> String {
-    let mut snailfish_numbers = process_data("./input/18.txt");
-    let result = sum_up_snailfish_numbers(&mut snailfish_numbers);
-    format!("Day 18: Snailfish (Part 1) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_18_snailfish::part_1::*;
+    use crate::year_2021::day_18_snailfish::parse;
+    use crate::year_2021::day_18_snailfish::part_1::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let mut snailfish_numbers = process_data("./test_input/18.txt");
+        let snailfish_numbers = parse(&read_example("18.txt").unwrap()).unwrap();
         assert_eq!(snailfish_numbers.len(), 10);
         assert_eq!(
             snailfish_numbers[7],
             Vec::from([(2, 9), (2, 3), (3, 9), (3, 9), (3, 6), (4, 4), (4, 9)])
         );
-        let result = sum_up_snailfish_numbers(&mut snailfish_numbers);
+        let result = sum_up_snailfish_numbers(&snailfish_numbers);
         assert_eq!(result, 4140);
     }
 
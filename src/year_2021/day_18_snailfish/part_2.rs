@@ -1,26 +1,3 @@
-fn process_data(path: &str) -> Vec<Vec<(u8, usize)>> {
-    let file_content = std::fs::read_to_string(path).unwrap();
-    let mut snailfish_numbers: Vec<Vec<(u8, usize)>> = Vec::new();
-
-    for line in file_content.lines() {
-        let mut depth = 0;
-        let mut snailfish_number = Vec::new();
-        for c in line.chars() {
-            match c {
-                '[' => depth += 1,
-                ']' => depth -= 1,
-                digit if digit.is_ascii_digit() => {
-                    let value = c.to_digit(10).unwrap() as usize;
-                    snailfish_number.push((depth, value));
-                }
-                _ => {}
-            }
-        }
-        snailfish_numbers.push(snailfish_number);
-    }
-    snailfish_numbers
-}
-
 fn join_two_snailfish_numbers(lhs: &[(u8, usize)], rhs: &[(u8, usize)]) -> Vec<(u8, usize)> {
     let mut joined_snailfish_number: Vec<(u8, usize)> = Vec::new();
     joined_snailfish_number.extend(lhs);
@@ -89,7 +66,7 @@ fn calculate_magnitude(snailfish_number: &mut Vec<(u8, usize)>) {
     }
 }
 
-fn find_highest_possible_magnitude(snailfish_numbers: &[Vec<(u8, usize)>]) -> usize {
+pub fn find_highest_possible_magnitude(snailfish_numbers: &[Vec<(u8, usize)>]) -> usize {
     let mut highest_magnitude: usize = 0;
 
     for snailfish_number in snailfish_numbers {
@@ -110,19 +87,15 @@ fn find_highest_possible_magnitude(snailfish_numbers: &[Vec<(u8, usize)>]) -> us
     highest_magnitude
 }
 
-pub fn solve() -> String {
-    let snailfish_numbers = process_data("./input/18.txt");
-    let result = find_highest_possible_magnitude(&snailfish_numbers);
-    format!("Day 18: Snailfish (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_18_snailfish::part_2::*;
+    use crate::year_2021::day_18_snailfish::parse;
+    use crate::year_2021::day_18_snailfish::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let snailfish_numbers = process_data("./test_input/18.txt");
+        let snailfish_numbers = parse(&read_example("18.txt").unwrap()).unwrap();
         assert_eq!(snailfish_numbers.len(), 10);
         assert_eq!(
             snailfish_numbers[7],
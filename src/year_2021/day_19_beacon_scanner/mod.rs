@@ -0,0 +1,58 @@
+use crate::answer::Answer;
+use crate::geometry::Vector3;
+use crate::parse::{integers, sections, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses the beacons reported by each scanner, relative to that scanner.
+pub fn parse(input: &str) -> Result<Vec<Vec<Vector3>>, ParseError> {
+    let scanners = sections(input);
+
+    if scanners.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected a scanner"));
+    }
+
+    scanners
+        .into_iter()
+        .map(|scanner| {
+            let mut lines = scanner.lines();
+
+            match lines.next() {
+                Some(header) if header.starts_with("---") => lines
+                    .map(|line| {
+                        let coordinates: [i64; 3] = integers(input, line, "a coordinate")?
+                            .try_into()
+                            .map_err(|_| {
+                                ParseError::at(input, line, "expected three coordinates")
+                            })?;
+                        Ok(Vector3::from(coordinates))
+                    })
+                    .collect(),
+                _ => Err(ParseError::at(input, scanner, "expected a scanner header")),
+            }
+        })
+        .collect()
+}
+
+pub struct BeaconScanner;
+
+impl Solution for BeaconScanner {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+
+    type Input = Vec<Vec<Vector3>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(scanners_data: &Self::Input) -> Answer {
+        part_1::count_beacons(scanners_data).into()
+    }
+
+    fn part_2(scanners_data: &Self::Input) -> Answer {
+        part_2::find_maximum_distance_between_scanners(scanners_data).into()
+    }
+}
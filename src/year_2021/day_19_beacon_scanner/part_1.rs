@@ -0,0 +1,171 @@
+use crate::geometry::{Rotation, Vector3};
+use std::collections::{HashMap, HashSet};
+
+struct Scanner {
+    beacons: Vec<Vector3>,
+    pairwise_distances_map: HashMap<[i64; 3], (Vector3, Vector3)>,
+    pairwise_distances: HashSet<[i64; 3]>,
+}
+
+impl Scanner {
+    fn new(beacons: &[Vector3]) -> Self {
+        Self {
+            beacons: beacons.to_vec(),
+            pairwise_distances_map: HashMap::new(),
+            pairwise_distances: HashSet::new(),
+        }
+    }
+
+    /// Distances between beacons are compared whatever the orientation of the scanners, as the
+    /// sorted absolute differences of their coordinates.
+    fn calculate_pairwise_distances(&mut self) {
+        for (index, beacon) in self.beacons.iter().enumerate() {
+            for other_beacon in &self.beacons[index + 1..] {
+                let mut distance = (*beacon - *other_beacon).abs().to_array();
+                distance.sort_unstable();
+                self.pairwise_distances_map
+                    .insert(distance, (*beacon, *other_beacon));
+                self.pairwise_distances.insert(distance);
+            }
+        }
+    }
+}
+
+fn find_matching_scanners(scanner: &Scanner, other_scanner: &Scanner) -> bool {
+    let intersection: HashSet<_> = scanner
+        .pairwise_distances
+        .intersection(&other_scanner.pairwise_distances)
+        .clone()
+        .collect::<HashSet<_>>();
+
+    intersection.len() >= 66
+}
+
+fn find_matching_beacons_pairs(
+    scanner: &Scanner,
+    other_scanner: &Scanner,
+) -> Vec<(Vector3, Vector3)> {
+    let mut matching_pair_distances = Vec::new();
+
+    for (distance_value, point_pair) in &scanner.pairwise_distances_map {
+        if let Some(other_point_pair) = other_scanner.pairwise_distances_map.get(distance_value) {
+            matching_pair_distances.push((*point_pair, *other_point_pair));
+        }
+    }
+
+    let mut real_pairs_counts: HashMap<Vector3, HashMap<Vector3, usize>> = HashMap::new();
+
+    for ((pair_1, pair_2), (pair_3, pair_4)) in matching_pair_distances {
+        let update_count =
+            |map: &mut HashMap<Vector3, HashMap<Vector3, usize>>, p1: Vector3, p2: Vector3| {
+                map.entry(p1)
+                    .or_default()
+                    .entry(p2)
+                    .and_modify(|count| *count += 1)
+                    .or_insert(1);
+            };
+
+        update_count(&mut real_pairs_counts, pair_1, pair_3);
+        update_count(&mut real_pairs_counts, pair_1, pair_4);
+        update_count(&mut real_pairs_counts, pair_2, pair_3);
+        update_count(&mut real_pairs_counts, pair_2, pair_4);
+    }
+
+    let mut real_pairs = Vec::new();
+
+    for (point, counts) in real_pairs_counts {
+        if let Some((best_match, _count)) = counts.iter().max_by_key(|&(_, count)| count) {
+            real_pairs.push((point, *best_match));
+        }
+    }
+
+    real_pairs
+}
+
+fn determine_rotation(beacon_pairs: &[(Vector3, Vector3)]) -> Rotation {
+    let (point_a, other_point_a) = beacon_pairs[0];
+    let (point_b, other_point_b) = beacon_pairs[1];
+    let dist_a_b = point_a - point_b;
+    let other_dist_a_b = other_point_a - other_point_b;
+
+    Rotation::all()
+        .into_iter()
+        .find(|&rotation| rotation * other_dist_a_b == dist_a_b)
+        .expect("The matching beacons should be in the same place in one of the orientations")
+}
+
+/// Position of the other scanner relative to the first one.
+fn determine_displacement(beacon_pairs: &[(Vector3, Vector3)], rotation: Rotation) -> Vector3 {
+    let (point_a, other_point_a) = beacon_pairs[0];
+    let displacement = point_a - rotation * other_point_a;
+
+    let (point_b, other_point_b) = beacon_pairs[1];
+    assert_eq!(point_b, rotation * other_point_b + displacement);
+
+    displacement
+}
+
+fn rotate_and_align_beacons(
+    scanner: &Scanner,
+    rotation: Rotation,
+    displacement: Vector3,
+) -> Vec<Vector3> {
+    scanner
+        .beacons
+        .iter()
+        .map(|&beacon| rotation * beacon + displacement)
+        .collect()
+}
+
+pub fn count_beacons(scanners_data: &[Vec<Vector3>]) -> usize {
+    let mut scanners: Vec<Scanner> = scanners_data
+        .iter()
+        .map(|beacons| Scanner::new(beacons))
+        .collect();
+
+    for scanner in &mut scanners {
+        scanner.calculate_pairwise_distances()
+    }
+
+    while scanners.len() > 1 {
+        for index in 1..scanners.len() {
+            let is_match = find_matching_scanners(&scanners[0], &scanners[index]);
+            if is_match {
+                let matching_beacons_pairs =
+                    find_matching_beacons_pairs(&scanners[0], &scanners[index]);
+                let rotation = determine_rotation(&matching_beacons_pairs);
+                let displacement = determine_displacement(&matching_beacons_pairs, rotation);
+                let aligned_beacons =
+                    rotate_and_align_beacons(&scanners[index], rotation, displacement);
+
+                scanners[0].beacons.extend(aligned_beacons);
+                scanners[0].beacons.sort_unstable();
+                scanners[0].beacons.dedup();
+                scanners[0].calculate_pairwise_distances();
+                scanners.remove(index);
+                break;
+            }
+        }
+    }
+
+    scanners[0].beacons.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_19_beacon_scanner::parse;
+    use crate::year_2021::day_19_beacon_scanner::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn read_data_from_file_and_convert_to_vector() {
+        let scanners_data = parse(&read_example("19.txt").unwrap()).unwrap();
+        assert_eq!(scanners_data.len(), 5);
+        assert_eq!(scanners_data[0].len(), 25);
+        let mut scanner = Scanner::new(&scanners_data[1]);
+        scanner.calculate_pairwise_distances();
+        assert_eq!(scanner.pairwise_distances.len(), 300);
+        let result = count_beacons(&scanners_data);
+        assert_eq!(result, 79);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::{only_chars, section_pair, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses the image enhancement algorithm and the pixels of the input image, `true` if lit.
+pub fn parse(input: &str) -> Result<([bool; 512], Grid<bool>), ParseError> {
+    let (algorithm_line, image) = section_pair(input, input, "an input image")?;
+    let algorithm: [bool; 512] = only_chars(input, algorithm_line, "#.", "'#' or '.'")?
+        .chars()
+        .map(|c| c == '#')
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|values: Vec<bool>| {
+            ParseError::at(
+                input,
+                algorithm_line,
+                format!("expected 512 values, found {}", values.len()),
+            )
+        })?;
+
+    let pixels = Grid::parse(input, image, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    Ok((algorithm, pixels))
+}
+
+struct ImgState;
+
+impl ImgState {
+    const UNSEEN_PIXELS_ARE_LIT: bool = true;
+    const UNSEEN_PIXELS_ARE_UNLIT: bool = false;
+}
+
+struct Image {
+    pixels: Grid<bool>,
+    state: bool,
+}
+
+/// Reads the 3x3 section around `(x, y)` as a binary number, with pixels outside the image
+/// given by its state.
+fn calculate_index_of_enhancement_algorithm((x, y): (isize, isize), img: &Image) -> usize {
+    img.pixels.window((x, y), 1).fold(0, |number, pixel| {
+        number << 1 | pixel.copied().unwrap_or(img.state) as usize
+    })
+}
+
+/// Enhances the image `num_of_rounds` times and counts its lit pixels.
+pub fn enhance_image(pixels: &Grid<bool>, algorithm: &[bool; 512], num_of_rounds: usize) -> usize {
+    let mut img = Image {
+        pixels: pixels.clone(),
+        state: ImgState::UNSEEN_PIXELS_ARE_UNLIT,
+    };
+
+    for _ in 0..num_of_rounds {
+        let (width, height) = (img.pixels.width() + 2, img.pixels.height() + 2);
+        let new_pixels = Grid::from_fn(width, height, |(x, y)| {
+            algorithm
+                [calculate_index_of_enhancement_algorithm((x as isize - 1, y as isize - 1), &img)]
+        });
+
+        let new_state = match img.state {
+            ImgState::UNSEEN_PIXELS_ARE_LIT => algorithm[511],
+            ImgState::UNSEEN_PIXELS_ARE_UNLIT => algorithm[0],
+        };
+
+        img = Image {
+            pixels: new_pixels,
+            state: new_state,
+        }
+    }
+
+    img.pixels.values().filter(|&&pixel| pixel).count()
+}
+
+pub struct TrenchMap;
+
+impl Solution for TrenchMap {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Trench Map";
+
+    type Input = ([bool; 512], Grid<bool>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1((algorithm, pixels): &Self::Input) -> Answer {
+        part_1::count_lit_pixels(pixels, algorithm).into()
+    }
+
+    fn part_2((algorithm, pixels): &Self::Input) -> Answer {
+        part_2::count_lit_pixels(pixels, algorithm).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_20_trench_map::*;
+
+    #[test]
+    fn report_malformed_image() {
+        let input = format!("{}\n\n#..\n.o#\n", ".".repeat(512));
+        let error = parse(&input).err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(4, 2, "expected '#' or '.', found 'o'")
+        );
+        let error = parse("#.#\n\n#..\n").err().unwrap();
+        assert_eq!(error.message, "expected 512 values, found 3");
+    }
+}
@@ -0,0 +1,23 @@
+use crate::grid::Grid;
+use crate::year_2021::day_20_trench_map::enhance_image;
+
+/// Number of lit pixels after enhancing the image 50 times.
+pub fn count_lit_pixels(pixels: &Grid<bool>, algorithm: &[bool; 512]) -> usize {
+    enhance_image(pixels, algorithm, 50)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_20_trench_map::parse;
+    use crate::year_2021::day_20_trench_map::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let (algorithm, pixels) = parse(&read_example("20.txt").unwrap()).unwrap();
+        assert_eq!(algorithm.len(), 512);
+        assert_eq!((pixels.width(), pixels.height()), (5, 5));
+        let result = count_lit_pixels(&pixels, &algorithm);
+        assert_eq!(result, 3351);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::answer::Answer;
+use crate::parse::{parse_as, split_once, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses the starting positions of both players.
+pub fn parse(input: &str) -> Result<[u8; 2], ParseError> {
+    let positions = input
+        .lines()
+        .map(|line| {
+            let (_, position) = split_once(input, line, "starting position: ")?;
+
+            match parse_as(input, position, "a starting position")? {
+                pawn_pos @ 1..=10 => Ok(pawn_pos),
+                _ => Err(ParseError::at(
+                    input,
+                    position,
+                    format!("expected a starting position from 1 to 10, found {position:?}"),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    positions.try_into().map_err(|positions: Vec<u8>| {
+        ParseError::end_of_input(
+            input,
+            format!("expected 2 players, found {}", positions.len()),
+        )
+    })
+}
+
+pub struct DiracDice;
+
+impl Solution for DiracDice {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+
+    type Input = [u8; 2];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(starting_positions: &Self::Input) -> Answer {
+        part_1::play_dirac_dice(starting_positions).into()
+    }
+
+    fn part_2(&[first, second]: &Self::Input) -> Answer {
+        let mut memo = HashMap::new();
+        let scores = part_2::play_quantum_dirac_dice(
+            &mut memo,
+            part_2::Player::new(first),
+            part_2::Player::new(second),
+        );
+        (*scores.iter().max().unwrap()).into()
+    }
+}
@@ -0,0 +1,43 @@
+struct Player {
+    pawn_pos: usize,
+    score: usize,
+}
+
+pub fn play_dirac_dice(starting_positions: &[u8; 2]) -> usize {
+    let mut players: Vec<Player> = starting_positions
+        .iter()
+        .map(|&pawn_pos| Player {
+            pawn_pos: pawn_pos as usize,
+            score: 0,
+        })
+        .collect();
+    let mut die = (1..=100).cycle().enumerate();
+    let mut roll = || die.next().unwrap().1;
+
+    loop {
+        for player in &mut players {
+            player.pawn_pos = (player.pawn_pos + roll() + roll() + roll() - 1) % 10 + 1;
+            player.score += player.pawn_pos;
+
+            if player.score >= 1000 {
+                return players.iter().map(|player| player.score).min().unwrap()
+                    * die.next().unwrap().0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_21_dirac_dice::parse;
+    use crate::year_2021::day_21_dirac_dice::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let starting_positions = parse(&read_example("21.txt").unwrap()).unwrap();
+        assert_eq!(starting_positions, [4, 8]);
+        let result = play_dirac_dice(&starting_positions);
+        assert_eq!(result, 739785);
+    }
+}
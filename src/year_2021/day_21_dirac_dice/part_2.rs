@@ -1,23 +1,18 @@
 use std::collections::HashMap;
 
 #[derive(Clone, Copy, Hash, Eq, PartialEq)]
-struct Player {
+pub struct Player {
     pawn_pos: u8,
     score: u8,
 }
 
-fn process_data(path: &str) -> Vec<Player> {
-    let file_content = std::fs::read_to_string(path).unwrap();
-    file_content
-        .lines()
-        .map(|line| Player {
-            pawn_pos: line.split_whitespace().last().unwrap().parse().unwrap(),
-            score: 0,
-        })
-        .collect()
+impl Player {
+    pub fn new(pawn_pos: u8) -> Self {
+        Self { pawn_pos, score: 0 }
+    }
 }
 
-fn play_quantum_dirac_dice(
+pub fn play_quantum_dirac_dice(
     memo: &mut HashMap<(Player, Player), [usize; 2]>,
     active: Player,
     other: Player,
@@ -52,26 +47,17 @@ fn play_quantum_dirac_dice(
     game_score
 }
 
-pub fn solve() -> String {
-    let players = process_data("./input/21.txt");
-    let mut memo: HashMap<(Player, Player), [usize; 2]> = HashMap::new();
-    let scores = play_quantum_dirac_dice(&mut memo, players[0], players[1]);
-    format!(
-        "Day 21: Dirac Dice (Part 2) answer: {}",
-        scores.iter().max().unwrap()
-    )
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_21_dirac_dice::part_2::*;
+    use crate::year_2021::day_21_dirac_dice::parse;
+    use crate::year_2021::day_21_dirac_dice::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let players = process_data("./test_input/21.txt");
-        assert_eq!(players.len(), 2);
+        let [first, second] = parse(&read_example("21.txt").unwrap()).unwrap();
         let mut memo: HashMap<(Player, Player), [usize; 2]> = HashMap::new();
-        let scores = play_quantum_dirac_dice(&mut memo, players[0], players[1]);
+        let scores = play_quantum_dirac_dice(&mut memo, Player::new(first), Player::new(second));
         assert_eq!(*scores.iter().max().unwrap(), 444356092776315);
     }
 }
@@ -0,0 +1,119 @@
+use crate::answer::Answer;
+use crate::geometry::{Cuboid, Vector3};
+use crate::parse::{range, split_once, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// A reboot step turning the cubes within a cuboid on or off.
+#[derive(Clone)]
+pub struct Step {
+    is_on: bool,
+    cuboid: Cuboid,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (is_on, cuboid) = parse_step(input, line)?;
+            Ok(Step { is_on, cuboid })
+        })
+        .collect()
+}
+
+fn parse_step(input: &str, line: &str) -> Result<(bool, Cuboid), ParseError> {
+    let (state, cuboid) = split_once(input, line, " ")?;
+    let is_on = match state {
+        "on" => true,
+        "off" => false,
+        _ => {
+            return Err(ParseError::at(
+                input,
+                state,
+                format!("expected \"on\" or \"off\", found {state:?}"),
+            ))
+        }
+    };
+    let mut ranges = cuboid.split(',');
+    let (mut min, mut max) = ([0; 3], [0; 3]);
+
+    for (index, axis) in ["x", "y", "z"].into_iter().enumerate() {
+        let text = ranges
+            .next()
+            .ok_or_else(|| ParseError::at(input, cuboid, format!("expected {axis:?} range")))?;
+        (min[index], max[index]) = range(input, text, axis)?.into_inner();
+        if min[index] > max[index] {
+            return Err(ParseError::at(
+                input,
+                text,
+                format!("expected the start of {text:?} not to exceed its end"),
+            ));
+        }
+    }
+
+    if let Some(text) = ranges.next() {
+        return Err(ParseError::at(
+            input,
+            text,
+            format!("expected the end of the line, found {text:?}"),
+        ));
+    }
+
+    Ok((is_on, Cuboid::new(Vector3::from(min), Vector3::from(max))))
+}
+
+pub struct ReactorReboot;
+
+impl Solution for ReactorReboot {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(steps: &Self::Input) -> Answer {
+        let mut steps = steps.clone();
+        part_1::discard_steps_exceeding_range(&mut steps, -50, 50);
+        part_1::calculate_how_many_cubes_are_on_after_initialization(&steps).into()
+    }
+
+    fn part_2(steps: &Self::Input) -> Answer {
+        part_2::calculate_how_many_cubes_are_on_after_initialization(steps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_22_reactor_reboot::*;
+
+    #[test]
+    fn reject_inverted_range() {
+        let input = "on x=1..2,y=1..2,z=1..2\noff x=1..2,y=5..-5,z=1..2";
+        assert_eq!(
+            parse(input).err(),
+            Some(ParseError::new(
+                2,
+                12,
+                "expected the start of \"y=5..-5\" not to exceed its end"
+            ))
+        );
+    }
+
+    #[test]
+    fn reject_extra_range() {
+        let input = "on x=1..2,y=1..2,z=1..2,w=3..4";
+        assert_eq!(
+            parse(input).err(),
+            Some(ParseError::new(
+                1,
+                25,
+                "expected the end of the line, found \"w=3..4\""
+            ))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::geometry::{Cuboid, Vector3};
+use crate::year_2021::day_22_reactor_reboot::Step;
+use std::collections::HashSet;
+
+pub fn discard_steps_exceeding_range(steps: &mut Vec<Step>, min_value: i64, max_value: i64) {
+    let region = Cuboid::new(
+        Vector3::new(min_value, min_value, min_value),
+        Vector3::new(max_value, max_value, max_value),
+    );
+    steps.retain(|step| region.encloses(&step.cuboid));
+}
+
+pub fn calculate_how_many_cubes_are_on_after_initialization(steps: &[Step]) -> usize {
+    let mut on_cubes: HashSet<Vector3> = HashSet::new();
+
+    for step in steps {
+        for cube in step.cuboid.points() {
+            match step.is_on {
+                true => on_cubes.insert(cube),
+                false => on_cubes.remove(&cube),
+            };
+        }
+    }
+
+    on_cubes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_22_reactor_reboot::parse;
+    use crate::year_2021::day_22_reactor_reboot::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let mut steps = parse(&read_example("22_1.txt").unwrap()).unwrap();
+        discard_steps_exceeding_range(&mut steps, -50, 50);
+        assert_eq!(steps.len(), 20);
+        let result = calculate_how_many_cubes_are_on_after_initialization(&steps);
+        assert_eq!(result, 590784);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::year_2021::day_22_reactor_reboot::Step;
+
+pub fn calculate_how_many_cubes_are_on_after_initialization(steps: &[Step]) -> i64 {
+    let mut processed_steps: Vec<Step> = Vec::new();
+
+    for step in steps {
+        let mut new_processed_steps = Vec::new();
+
+        if step.is_on {
+            new_processed_steps.push(step.clone());
+        }
+
+        for processed_step in processed_steps.iter() {
+            if let Some(intersection) = step.cuboid.intersection(&processed_step.cuboid) {
+                new_processed_steps.push(Step {
+                    is_on: !processed_step.is_on,
+                    cuboid: intersection,
+                });
+            }
+        }
+
+        processed_steps.extend(new_processed_steps);
+    }
+
+    processed_steps
+        .iter()
+        .map(|processed_step| match processed_step.is_on {
+            true => processed_step.cuboid.volume(),
+            false => -processed_step.cuboid.volume(),
+        })
+        .sum::<i64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_22_reactor_reboot::parse;
+    use crate::year_2021::day_22_reactor_reboot::part_2::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let steps = parse(&read_example("22_2.txt").unwrap()).unwrap();
+        assert_eq!(steps.len(), 60);
+        let result = calculate_how_many_cubes_are_on_after_initialization(&steps);
+        assert_eq!(result, 2758514936282235);
+    }
+}
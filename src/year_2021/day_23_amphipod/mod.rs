@@ -0,0 +1,86 @@
+use crate::answer::Answer;
+use crate::parse::{only_chars, ParseError};
+use crate::solution::Solution;
+
+pub mod part_1;
+pub mod part_2;
+
+/// Parses the amphipods in the side rooms, row by row, numbered from 1 for Amber to 4 for Desert.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut amphipods = Vec::new();
+
+    for line in input.lines() {
+        for b in only_chars(input, line, "#.ABCD ", "a wall, space or amphipod")?.bytes() {
+            if let b'A'..=b'D' = b {
+                amphipods.push((b - b'A') as usize + 1);
+            }
+        }
+    }
+
+    let is_complete = (1..=4).all(|kind| {
+        amphipods
+            .iter()
+            .filter(|&&amphipod| amphipod == kind)
+            .count()
+            == 2
+    });
+
+    match is_complete {
+        true => Ok(amphipods),
+        false => Err(ParseError::end_of_input(
+            input,
+            "expected two amphipods of each type in the side rooms",
+        )),
+    }
+}
+
+/// Energy spent by Amber, Bronze, Copper and Desert amphipods per step.
+const MOVEMENT_COST: [usize; 4] = [1, 10, 100, 1000];
+/// Hallway spaces in front of each side room.
+const ROOM_ENTRY: [usize; 4] = [2, 4, 6, 8];
+
+/// Lower bound of the energy left to spend: every amphipod outside its own room has to leave its
+/// room, if it is in one, and walk along the hallway into the first space of its own room. The
+/// state lists the 11 hallway spaces, then the spaces of each room from the hallway down.
+fn estimate_remaining_cost(burrow_state: &[usize]) -> usize {
+    let room_size = (burrow_state.len() - 11) / 4;
+    let mut cost = 0;
+
+    for (position, &amphipod) in burrow_state.iter().enumerate() {
+        if amphipod == 0 {
+            continue;
+        }
+
+        let home_room = amphipod - 1;
+        let (steps_out, hallway) = match position.checked_sub(11) {
+            None => (0, position),
+            Some(index) if index / room_size == home_room => continue,
+            Some(index) => (index % room_size + 1, ROOM_ENTRY[index / room_size]),
+        };
+        let steps = steps_out + hallway.abs_diff(ROOM_ENTRY[home_room]) + 1;
+        cost += steps * MOVEMENT_COST[home_room];
+    }
+
+    cost
+}
+
+pub struct Amphipod;
+
+impl Solution for Amphipod {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Amphipod";
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(amphipods: &Self::Input) -> Answer {
+        part_1::calculate_minimum_energy_cost(part_1::fill_burrow(amphipods)).into()
+    }
+
+    fn part_2(amphipods: &Self::Input) -> Answer {
+        part_2::calculate_minimum_energy_cost(part_2::fill_burrow(amphipods)).into()
+    }
+}
@@ -1,5 +1,5 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap};
+use crate::search::a_star;
+use crate::year_2021::day_23_amphipod::{estimate_remaining_cost, MOVEMENT_COST, ROOM_ENTRY};
 
 struct Amphipod;
 
@@ -8,14 +8,12 @@ impl Amphipod {
     const BRONZE: usize = 2;
     const COPPER: usize = 3;
     const DESERT: usize = 4;
-    const MOVEMENT_COST: [usize; 4] = [1, 10, 100, 1000];
 }
 
 struct Burrow;
 
 impl Burrow {
     const HALLWAY: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
-    const ROOM_ENTRY: [usize; 4] = [2, 4, 6, 8];
     const ROOMS: [[usize; 4]; 4] = [
         [11, 12, 13, 14],
         [15, 16, 17, 18],
@@ -31,8 +29,7 @@ fn generate_valid_moves(burrow_state: &[usize; 27]) -> Vec<(usize, usize, usize)
         if burrow_state[hallway] > 0 {
             let home_room = burrow_state[hallway] - 1;
             let room_size = 4;
-            let mut path = hallway.min(Burrow::ROOM_ENTRY[home_room])
-                ..=hallway.max(Burrow::ROOM_ENTRY[home_room]);
+            let mut path = hallway.min(ROOM_ENTRY[home_room])..=hallway.max(ROOM_ENTRY[home_room]);
             let path_len = path.end() - path.start() + 1;
             let is_path_unobstructed = path.all(|i| i == hallway || burrow_state[i] == 0);
             let room_states = Burrow::ROOMS[home_room].map(|index| burrow_state[index]);
@@ -51,9 +48,8 @@ fn generate_valid_moves(burrow_state: &[usize; 27]) -> Vec<(usize, usize, usize)
                 ));
             }
         } else {
-            for room in 0..=3 {
-                let mut path =
-                    Burrow::ROOM_ENTRY[room].min(hallway)..=Burrow::ROOM_ENTRY[room].max(hallway);
+            for (room, &entry) in ROOM_ENTRY.iter().enumerate() {
+                let mut path = entry.min(hallway)..=entry.max(hallway);
                 let path_len = path.end() - path.start() + 1;
                 let is_path_unobstructed = path.all(|i| i == hallway || burrow_state[i] == 0);
                 let room_states = Burrow::ROOMS[room].map(|index| burrow_state[index]);
@@ -75,76 +71,69 @@ fn generate_valid_moves(burrow_state: &[usize; 27]) -> Vec<(usize, usize, usize)
     valid_moves
 }
 
-fn calculate_minimum_energy_cost(burrow_state: [usize; 27]) -> usize {
+pub fn calculate_minimum_energy_cost(burrow_state: [usize; 27]) -> usize {
     let finish_condition: [usize; 27] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4,
     ];
-    let mut p_queue = BinaryHeap::from([(Reverse(0), burrow_state)]);
-    let mut minimum_cost: HashMap<[usize; 27], usize> = HashMap::from([(burrow_state, 0)]);
-
-    while let Some((Reverse(cost), state)) = p_queue.pop() {
-        if state == finish_condition {
-            return cost;
-        }
-
-        let valid_moves = generate_valid_moves(&state);
-
-        for (from, to, distance) in valid_moves {
-            let mut next_state = state;
-            let amphipod = next_state[from] - 1;
-            next_state[to] = amphipod + 1;
-            next_state[from] = 0;
-            let next_cost = cost + distance * Amphipod::MOVEMENT_COST[amphipod];
-
-            if next_cost < *minimum_cost.get(&next_state).unwrap_or(&usize::MAX) {
-                minimum_cost.insert(next_state, next_cost);
-                p_queue.push((Reverse(next_cost), next_state));
-            }
-        }
-    }
-
-    unreachable!()
+    let search = a_star(
+        burrow_state,
+        |&state| {
+            generate_valid_moves(&state)
+                .into_iter()
+                .map(move |(from, to, distance)| {
+                    let mut next_state = state;
+                    let amphipod = next_state[from] - 1;
+                    next_state[to] = amphipod + 1;
+                    next_state[from] = 0;
+                    (next_state, distance * MOVEMENT_COST[amphipod])
+                })
+        },
+        |state| *state == finish_condition,
+        |state| estimate_remaining_cost(state),
+    );
+
+    search
+        .path
+        .expect("The amphipods should be able to organize themselves")
+        .cost
 }
 
-fn process_data(path: &str) -> [usize; 27] {
-    let file_content = std::fs::read_to_string(path).unwrap();
-    let mut lines: Vec<&str> = file_content.lines().collect();
-    lines.splice(3..3, ["#D#C#B#A#", "#D#B#A#C#"].iter().cloned());
-    let file_content = lines.join("");
+pub fn fill_burrow(amphipods: &[usize]) -> [usize; 27] {
+    let mut amphipods = amphipods.to_vec();
+    amphipods.splice(
+        4..4,
+        [
+            Amphipod::DESERT,
+            Amphipod::COPPER,
+            Amphipod::BRONZE,
+            Amphipod::AMBER,
+            Amphipod::DESERT,
+            Amphipod::BRONZE,
+            Amphipod::AMBER,
+            Amphipod::COPPER,
+        ],
+    );
 
     let mut state: [usize; 27] = [0; 27];
     let room_size = 4;
 
-    for (i, b) in file_content
-        .bytes()
-        .filter(|b| *b >= b'A' && *b <= b'D')
-        .enumerate()
-    {
-        state[11 + room_size * (i % 4) + i / 4] = match b {
-            b'A' => Amphipod::AMBER,
-            b'B' => Amphipod::BRONZE,
-            b'C' => Amphipod::COPPER,
-            b'D' => Amphipod::DESERT,
-            _ => unreachable!(),
-        };
+    for (i, amphipod) in amphipods.into_iter().enumerate() {
+        state[11 + room_size * (i % 4) + i / 4] = amphipod;
     }
 
     state
 }
 
-pub fn solve() -> String {
-    let burrow_state = process_data("./input/23.txt");
-    let result = calculate_minimum_energy_cost(burrow_state);
-    format!("Day 23: Amphipod (Part 2) answer: {}", result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::day_23_amphipod::part_2::*;
+    use crate::year_2021::day_23_amphipod::parse;
+    use crate::year_2021::day_23_amphipod::part_2::*;
+    use crate::year_2021::read_example;
 
     #[test]
     fn solve_with_test_data() {
-        let burrow_state = process_data("./test_input/23.txt");
+        let amphipods = parse(&read_example("23.txt").unwrap()).unwrap();
+        let burrow_state = fill_burrow(&amphipods);
         let result = calculate_minimum_energy_cost(burrow_state);
         assert_eq!(result, 44169);
     }
@@ -0,0 +1,161 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub mod part_1;
+pub mod part_2;
+
+pub enum VariableOrNumber {
+    Variable(usize),
+    Number(i64),
+}
+
+impl VariableOrNumber {
+    fn get_value(&self, variables: &[i64; 4]) -> i64 {
+        match self {
+            VariableOrNumber::Variable(index) => variables[*index],
+            VariableOrNumber::Number(num) => *num,
+        }
+    }
+}
+
+pub enum Instruction {
+    Inp(usize),
+    Add(usize, VariableOrNumber),
+    Mul(usize, VariableOrNumber),
+    Div(usize, VariableOrNumber),
+    Mod(usize, VariableOrNumber),
+    Eql(usize, VariableOrNumber),
+}
+
+impl Instruction {
+    fn execute(&self, variables: &mut [i64; 4], input: Option<i64>) {
+        match self {
+            Instruction::Inp(var) => variables[*var] = input.unwrap(),
+            Instruction::Add(var, var_or_num) => variables[*var] += var_or_num.get_value(variables),
+            Instruction::Mul(var, var_or_num) => variables[*var] *= var_or_num.get_value(variables),
+            Instruction::Div(var, var_or_num) => variables[*var] /= var_or_num.get_value(variables),
+            Instruction::Mod(var, var_or_num) => variables[*var] %= var_or_num.get_value(variables),
+            Instruction::Eql(var, var_or_num) => {
+                variables[*var] = (variables[*var] == var_or_num.get_value(variables)) as i64
+            }
+        }
+    }
+}
+
+fn convert_to_instruction(input: &str, line: &str) -> Result<Instruction, ParseError> {
+    let instruction = line.split_whitespace().collect::<Vec<_>>();
+
+    let variables_index = |var: &str| match var {
+        "w" | "x" | "y" | "z" => Ok(var.as_bytes()[0] as usize - b'w' as usize),
+        _ => Err(ParseError::at(
+            input,
+            var,
+            format!("expected a variable w, x, y or z, found {var:?}"),
+        )),
+    };
+    let var_or_num = |operand: &str| match operand.parse::<i64>() {
+        Ok(number) => Ok(VariableOrNumber::Number(number)),
+        Err(_) => variables_index(operand).map(VariableOrNumber::Variable),
+    };
+
+    Ok(match instruction[..] {
+        ["inp", var] => Instruction::Inp(variables_index(var)?),
+        ["mul", var, operand] => Instruction::Mul(variables_index(var)?, var_or_num(operand)?),
+        ["add", var, operand] => Instruction::Add(variables_index(var)?, var_or_num(operand)?),
+        ["mod", var, operand] => Instruction::Mod(variables_index(var)?, var_or_num(operand)?),
+        ["div", var, operand] => Instruction::Div(variables_index(var)?, var_or_num(operand)?),
+        ["eql", var, operand] => Instruction::Eql(variables_index(var)?, var_or_num(operand)?),
+        _ => {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected an ALU instruction, found {line:?}"),
+            ))
+        }
+    })
+}
+
+/// Parses the MONAD program, made of one block of instructions per digit of the model number,
+/// each starting with `inp`.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = input
+        .lines()
+        .map(|line| convert_to_instruction(input, line))
+        .collect::<Result<_, _>>()?;
+    let blocks = instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::Inp(_)))
+        .count();
+
+    match instructions.first() {
+        Some(Instruction::Inp(_)) if blocks == 14 => Ok(instructions),
+        Some(Instruction::Inp(_)) => Err(ParseError::end_of_input(
+            input,
+            format!("expected 14 blocks starting with \"inp\", found {blocks}"),
+        )),
+        _ => Err(ParseError::at(
+            input,
+            input.lines().next().unwrap_or(""),
+            "expected the program to start with \"inp\"",
+        )),
+    }
+}
+
+pub struct ArithmeticLogicUnit;
+
+impl Solution for ArithmeticLogicUnit {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(instructions: &Self::Input) -> Answer {
+        let mut memo = HashMap::new();
+        part_1::find_the_largest_fourteen_digit_model_number(instructions, 0, [0; 4], &mut memo)
+            .unwrap()
+            .into()
+    }
+
+    fn part_2(instructions: &Self::Input) -> Answer {
+        let mut memo = HashMap::new();
+        part_2::find_the_smallest_fourteen_digit_model_number(instructions, 0, 0, &mut memo)
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_24_arithmetic_logic_unit::*;
+
+    #[test]
+    fn report_malformed_instruction() {
+        let program = "inp w\nadd z w\nmul x -1\n".repeat(14);
+        assert_eq!(parse(&program).unwrap().len(), 42);
+        assert_eq!(
+            parse(&program[6..]).err().unwrap().to_string(),
+            "line 1, column 1: expected the program to start with \"inp\""
+        );
+        assert_eq!(
+            parse("inp w\nadd z w\n").err().unwrap(),
+            ParseError::new(3, 1, "expected 14 blocks starting with \"inp\", found 1")
+        );
+        assert!(parse("").is_err());
+        let error = parse("inp w\nadd q 1\n").err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(2, 5, "expected a variable w, x, y or z, found \"q\"")
+        );
+        let error = parse("inp w\nsub x 1\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected an ALU instruction, found \"sub x 1\""
+        );
+    }
+}
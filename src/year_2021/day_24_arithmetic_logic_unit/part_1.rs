@@ -0,0 +1,45 @@
+use crate::year_2021::day_24_arithmetic_logic_unit::Instruction;
+use std::collections::HashMap;
+
+pub fn find_the_largest_fourteen_digit_model_number(
+    instructions: &[Instruction],
+    index: usize,
+    variables: [i64; 4],
+    memo: &mut HashMap<([i64; 4], usize), Option<String>>,
+) -> Option<String> {
+    if let Some(model_number) = memo.get(&(variables, index)) {
+        return model_number.clone();
+    }
+
+    for model_digit in (1..=9).rev() {
+        let mut next_variables = variables;
+        let mut next_index = index;
+        instructions[next_index].execute(&mut next_variables, Some(model_digit));
+        next_index += 1;
+
+        while let Some(instruction) = instructions.get(next_index) {
+            if matches!(instructions[next_index], Instruction::Inp(_)) {
+                if let Some(model_number) = find_the_largest_fourteen_digit_model_number(
+                    instructions,
+                    next_index,
+                    next_variables,
+                    memo,
+                ) {
+                    return Some(format!("{}{}", model_digit, model_number));
+                } else {
+                    break;
+                }
+            } else {
+                instruction.execute(&mut next_variables, None);
+                next_index += 1;
+            }
+        }
+
+        if next_variables[3] == 0 {
+            return Some(model_digit.to_string());
+        }
+    }
+
+    memo.insert((variables, index), None);
+    None
+}
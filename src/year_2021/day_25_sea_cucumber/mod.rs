@@ -0,0 +1,34 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub mod part_1;
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, input, "'>', 'v' or '.'", |c| {
+        ">v.".contains(c).then_some(c)
+    })
+}
+
+pub struct SeaCucumber;
+
+impl Solution for SeaCucumber {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+    const PARTS: u8 = 1;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(seafloor: &Self::Input) -> Answer {
+        part_1::simulate_sea_cucumbers_movement(&mut seafloor.clone()).into()
+    }
+
+    fn part_2(_input: &Self::Input) -> Answer {
+        unreachable!("Day 25 has only one part")
+    }
+}
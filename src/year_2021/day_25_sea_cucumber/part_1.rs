@@ -0,0 +1,53 @@
+use crate::grid::Grid;
+
+/// Moves every sea cucumber of the `herd` facing `direction` whose destination, wrapping around
+/// the edges, is empty.
+fn move_sea_cucumbers(
+    seafloor: &mut Grid<char>,
+    herd: char,
+    direction: (isize, isize),
+    sea_cucumber_moved: &mut bool,
+) {
+    let to_move: Vec<((usize, usize), (usize, usize))> = seafloor
+        .iter()
+        .filter(|&(_, &c)| c == herd)
+        .map(|(position, _)| (position, seafloor.wrapping_offset(position, direction)))
+        .filter(|&(_, destination)| seafloor[destination] == '.')
+        .collect();
+
+    *sea_cucumber_moved |= !to_move.is_empty();
+
+    for (position, destination) in to_move {
+        seafloor[position] = '.';
+        seafloor[destination] = herd;
+    }
+}
+
+pub fn simulate_sea_cucumbers_movement(seafloor: &mut Grid<char>) -> u64 {
+    for round_counter in 1.. {
+        let mut sea_cucumber_moved = false;
+
+        move_sea_cucumbers(seafloor, '>', (1, 0), &mut sea_cucumber_moved);
+        move_sea_cucumbers(seafloor, 'v', (0, 1), &mut sea_cucumber_moved);
+
+        if !sea_cucumber_moved {
+            return round_counter;
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year_2021::day_25_sea_cucumber::parse;
+    use crate::year_2021::day_25_sea_cucumber::part_1::*;
+    use crate::year_2021::read_example;
+
+    #[test]
+    fn solve_with_test_data() {
+        let mut seafloor = parse(&read_example("25.txt").unwrap()).unwrap();
+        let result = simulate_sea_cucumbers_movement(&mut seafloor);
+        assert_eq!(result, 58);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::registry::Puzzle;
+use day_01_sonar_sweep::SonarSweep;
+use day_02_dive::Dive;
+use day_03_binary_diagnostic::BinaryDiagnostic;
+use day_04_giant_squid::GiantSquid;
+use day_05_hydrothermal_venture::HydrothermalVenture;
+use day_06_lanternfish::Lanternfish;
+use day_07_the_treachery_of_whales::TheTreacheryOfWhales;
+use day_08_seven_segment_search::SevenSegmentSearch;
+use day_09_smoke_basin::SmokeBasin;
+use day_10_syntax_scoring::SyntaxScoring;
+use day_11_dumbo_octopus::DumboOctopus;
+use day_12_passage_pathing::PassagePathing;
+use day_13_transparent_origami::TransparentOrigami;
+use day_14_extended_polymerization::ExtendedPolymerization;
+use day_15_chiton::Chiton;
+use day_16_packet_decoder::PacketDecoder;
+use day_17_trick_shot::TrickShot;
+use day_18_snailfish::Snailfish;
+use day_19_beacon_scanner::BeaconScanner;
+use day_20_trench_map::TrenchMap;
+use day_21_dirac_dice::DiracDice;
+use day_22_reactor_reboot::ReactorReboot;
+use day_23_amphipod::Amphipod;
+use day_24_arithmetic_logic_unit::ArithmeticLogicUnit;
+use day_25_sea_cucumber::SeaCucumber;
+
+pub mod day_01_sonar_sweep;
+pub mod day_02_dive;
+pub mod day_03_binary_diagnostic;
+pub mod day_04_giant_squid;
+pub mod day_05_hydrothermal_venture;
+pub mod day_06_lanternfish;
+pub mod day_07_the_treachery_of_whales;
+pub mod day_08_seven_segment_search;
+pub mod day_09_smoke_basin;
+pub mod day_10_syntax_scoring;
+pub mod day_11_dumbo_octopus;
+pub mod day_12_passage_pathing;
+pub mod day_13_transparent_origami;
+pub mod day_14_extended_polymerization;
+pub mod day_15_chiton;
+pub mod day_16_packet_decoder;
+pub mod day_17_trick_shot;
+pub mod day_18_snailfish;
+pub mod day_19_beacon_scanner;
+pub mod day_20_trench_map;
+pub mod day_21_dirac_dice;
+pub mod day_22_reactor_reboot;
+pub mod day_23_amphipod;
+pub mod day_24_arithmetic_logic_unit;
+pub mod day_25_sea_cucumber;
+
+pub const YEAR: u16 = 2021;
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::part_1::<SonarSweep>(YEAR),
+    Puzzle::part_2::<SonarSweep>(YEAR),
+    Puzzle::part_1::<Dive>(YEAR),
+    Puzzle::part_2::<Dive>(YEAR),
+    Puzzle::part_1::<BinaryDiagnostic>(YEAR),
+    Puzzle::part_2::<BinaryDiagnostic>(YEAR),
+    Puzzle::part_1::<GiantSquid>(YEAR),
+    Puzzle::part_2::<GiantSquid>(YEAR),
+    Puzzle::part_1::<HydrothermalVenture>(YEAR),
+    Puzzle::part_2::<HydrothermalVenture>(YEAR),
+    Puzzle::part_1::<Lanternfish>(YEAR),
+    Puzzle::part_2::<Lanternfish>(YEAR),
+    Puzzle::part_1::<TheTreacheryOfWhales>(YEAR),
+    Puzzle::part_2::<TheTreacheryOfWhales>(YEAR),
+    Puzzle::part_1::<SevenSegmentSearch>(YEAR),
+    Puzzle::part_2::<SevenSegmentSearch>(YEAR),
+    Puzzle::part_1::<SmokeBasin>(YEAR),
+    Puzzle::part_2::<SmokeBasin>(YEAR),
+    Puzzle::part_1::<SyntaxScoring>(YEAR),
+    Puzzle::part_2::<SyntaxScoring>(YEAR),
+    Puzzle::part_1::<DumboOctopus>(YEAR),
+    Puzzle::part_2::<DumboOctopus>(YEAR),
+    Puzzle::part_1::<PassagePathing>(YEAR),
+    Puzzle::part_2::<PassagePathing>(YEAR),
+    Puzzle::part_1::<TransparentOrigami>(YEAR),
+    Puzzle::part_2::<TransparentOrigami>(YEAR),
+    Puzzle::part_1::<ExtendedPolymerization>(YEAR),
+    Puzzle::part_2::<ExtendedPolymerization>(YEAR),
+    Puzzle::part_1::<Chiton>(YEAR),
+    Puzzle::part_2::<Chiton>(YEAR),
+    Puzzle::part_1::<PacketDecoder>(YEAR),
+    Puzzle::part_2::<PacketDecoder>(YEAR),
+    Puzzle::part_1::<TrickShot>(YEAR),
+    Puzzle::part_2::<TrickShot>(YEAR),
+    Puzzle::part_1::<Snailfish>(YEAR),
+    Puzzle::part_2::<Snailfish>(YEAR),
+    Puzzle::part_1::<BeaconScanner>(YEAR),
+    Puzzle::part_2::<BeaconScanner>(YEAR),
+    Puzzle::part_1::<TrenchMap>(YEAR),
+    Puzzle::part_2::<TrenchMap>(YEAR),
+    Puzzle::part_1::<DiracDice>(YEAR),
+    Puzzle::part_2::<DiracDice>(YEAR),
+    Puzzle::part_1::<ReactorReboot>(YEAR),
+    Puzzle::part_2::<ReactorReboot>(YEAR),
+    Puzzle::part_1::<Amphipod>(YEAR),
+    Puzzle::part_2::<Amphipod>(YEAR),
+    Puzzle::part_1::<ArithmeticLogicUnit>(YEAR),
+    Puzzle::part_2::<ArithmeticLogicUnit>(YEAR),
+    Puzzle::part_1::<SeaCucumber>(YEAR),
+];
+
+/// Reads an example input of this event, e.g. `01.txt` or `22_2.txt`.
+#[cfg(test)]
+pub(crate) fn read_example(file_name: &str) -> std::io::Result<String> {
+    crate::input::read_example(YEAR, file_name)
+}
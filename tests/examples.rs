@@ -0,0 +1,48 @@
+use advent_of_code_2021::input::{self, EXAMPLES_DIRECTORY};
+use advent_of_code_2021::{examples, registry};
+use std::collections::HashSet;
+use std::path::Path;
+
+#[test]
+fn solve_every_example_in_the_manifest() {
+    for year in registry::years() {
+        let manifest = examples::read_manifest(year).unwrap();
+        let failures: Vec<String> = manifest
+            .iter()
+            .filter_map(|example| example.check().err())
+            .collect();
+
+        assert!(
+            failures.is_empty(),
+            "{} of {} examples failed:\n{}",
+            failures.len(),
+            manifest.len(),
+            failures.join("\n")
+        );
+    }
+}
+
+#[test]
+fn list_every_example_input_in_the_manifest() {
+    for year in registry::years() {
+        let manifest = examples::read_manifest(year).unwrap();
+        let listed: HashSet<&str> = manifest
+            .iter()
+            .map(|example| example.file_name.as_str())
+            .collect();
+
+        for entry in
+            std::fs::read_dir(input::year_directory(Path::new(EXAMPLES_DIRECTORY), year)).unwrap()
+        {
+            let file_name = entry.unwrap().file_name().into_string().unwrap();
+            let is_input = file_name.ends_with(".txt") && file_name != examples::MANIFEST_FILE_NAME;
+            assert!(
+                !is_input || listed.contains(file_name.as_str()),
+                "{} is not listed in the {} {}",
+                file_name,
+                year,
+                examples::MANIFEST_FILE_NAME
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use advent_of_code_2021::year_2021::*;
+use advent_of_code_2021::*;
+
+fn read_test_input(file_name: &str) -> String {
+    input::read_example(year_2021::YEAR, file_name).unwrap()
+}
+
+#[test]
+fn solve_with_public_parsers_and_solvers() {
+    let report = day_01_sonar_sweep::parse(&read_test_input("01.txt")).unwrap();
+    let result = day_01_sonar_sweep::part_2::count_number_of_measurement_sums_increases(&report);
+    assert_eq!(result, 5);
+
+    let caves = day_12_passage_pathing::parse(&read_test_input("12.txt")).unwrap();
+    let start_id = &caves.iter().find(|cave| cave.name == "start").unwrap().id;
+    let end_id = &caves.iter().find(|cave| cave.name == "end").unwrap().id;
+    let result = day_12_passage_pathing::part_2::count_paths(
+        start_id,
+        start_id,
+        end_id,
+        &mut Vec::new(),
+        &caves,
+        false,
+    );
+    assert_eq!(result, 36);
+
+    let formula = day_14_extended_polymerization::parse(&read_test_input("14.txt")).unwrap();
+    let mut polymer_pair_form =
+        day_14_extended_polymerization::part_2::count_pairs(&formula.template);
+    day_14_extended_polymerization::part_2::grow_polymer(
+        &mut polymer_pair_form,
+        &formula.rules,
+        10,
+    );
+    let char_counters = day_14_extended_polymerization::part_2::count_each_char_occurrence(
+        &formula.template,
+        &polymer_pair_form,
+    );
+    let result = day_14_extended_polymerization::part_2::calculate_score_from_min_and_max_value(
+        &char_counters,
+    );
+    assert_eq!(result, 1588);
+
+    let (algorithm, pixels) = day_20_trench_map::parse(&read_test_input("20.txt")).unwrap();
+    assert_eq!(day_20_trench_map::enhance_image(&pixels, &algorithm, 2), 35);
+}
+
+#[test]
+fn solve_through_the_registry() {
+    let result = year_2021::PUZZLES[0].solve(&read_test_input("01.txt"));
+    assert_eq!(result, Ok(answer::Answer::Integer(7)));
+
+    let error = year_2021::PUZZLES[0].solve("199\n2x0\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    let [part_1, part_2] = &year_2021::PUZZLES[..2] else {
+        unreachable!()
+    };
+    let model = (part_1.parse)(&read_test_input("01.txt")).unwrap();
+    assert_eq!((part_1.run)(&*model), answer::Answer::Integer(7));
+    assert_eq!((part_2.run)(&*model), answer::Answer::Integer(5));
+}
+
+#[test]
+fn solve_through_the_solution_trait() {
+    use day_09_smoke_basin::SmokeBasin;
+    use solution::Solution;
+
+    assert_eq!(SmokeBasin::DAY, 9);
+    assert_eq!(SmokeBasin::TITLE, "Smoke Basin");
+    let heightmap = SmokeBasin::parse(&read_test_input("09.txt")).unwrap();
+    assert_eq!(SmokeBasin::part_1(&heightmap).to_string(), "15");
+    assert_eq!(SmokeBasin::part_2(&heightmap).to_string(), "1134");
+}
+
+#[test]
+fn verify_examples_against_expected_answers() {
+    use input::InputSource;
+    use verify::{Answers, Verdict};
+
+    let answers = Answers::read(std::path::Path::new("./test_input/2021/answers.toml")).unwrap();
+    let source: InputSource = "./test_input".parse().unwrap();
+    let mut verified = 0;
+
+    for puzzle in year_2021::PUZZLES.iter() {
+        let Ok(input) = source.read(puzzle.year, puzzle.day) else {
+            continue;
+        };
+        let answer = puzzle.solve(&input).unwrap();
+        assert_eq!(
+            answers.check(puzzle.day, puzzle.part, &answer),
+            Verdict::Pass,
+            "Day {} part {}",
+            puzzle.day,
+            puzzle.part
+        );
+        verified += 1;
+    }
+
+    assert_eq!(verified, answers.len());
+}